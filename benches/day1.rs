@@ -0,0 +1,82 @@
+use advent_of_code_2023::day1::{first_digit, first_named_digit, last_digit, last_named_digit};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// A representative mix of lines: short lines, leading word-digits,
+/// overlapping words and digit-free lines
+const LINES: [&str; 8] = [
+    "7v",
+    "ab",
+    "threeabc4def",
+    "sevenine2xyz",
+    "eightwo",
+    "zoneight234",
+    "abcdefghijklmnopqrstuvwxyz",
+    "pqrstuvwxyzpqrstuvwxyzpqrstuvwxyzpqrstuvwxyz",
+];
+
+fn bench_digits(c: &mut Criterion) {
+    let lines = LINES.map(str::as_bytes);
+
+    c.bench_function("first_digit", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|l| first_digit(black_box(l)))
+                .sum::<u64>()
+        })
+    });
+    c.bench_function("last_digit", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|l| last_digit(black_box(l)))
+                .sum::<u64>()
+        })
+    });
+    c.bench_function("first_named_digit", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|l| first_named_digit(black_box(l)))
+                .sum::<u64>()
+        })
+    });
+    c.bench_function("last_named_digit", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|l| last_named_digit(black_box(l)))
+                .sum::<u64>()
+        })
+    });
+}
+
+/// Long digit-sparse lines, where the `memchr` scanners should overtake the scalar ones
+#[cfg(feature = "memchr")]
+fn bench_digits_memchr(c: &mut Criterion) {
+    use advent_of_code_2023::day1::{first_digit_memchr, last_digit_memchr};
+
+    for len in [16, 64, 256, 1024, 4096] {
+        let mut line = vec![b'x'; len];
+        line[len / 2] = b'5';
+
+        c.bench_function(&format!("first_digit/{len}"), |b| {
+            b.iter(|| first_digit(black_box(&line)))
+        });
+        c.bench_function(&format!("first_digit_memchr/{len}"), |b| {
+            b.iter(|| first_digit_memchr(black_box(&line)))
+        });
+        c.bench_function(&format!("last_digit/{len}"), |b| {
+            b.iter(|| last_digit(black_box(&line)))
+        });
+        c.bench_function(&format!("last_digit_memchr/{len}"), |b| {
+            b.iter(|| last_digit_memchr(black_box(&line)))
+        });
+    }
+}
+
+#[cfg(feature = "memchr")]
+criterion_group!(benches, bench_digits, bench_digits_memchr);
+#[cfg(not(feature = "memchr"))]
+criterion_group!(benches, bench_digits);
+criterion_main!(benches);
@@ -0,0 +1,182 @@
+use std::{env, ffi::OsString, path::PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, io, path::Path};
+
+use crate::{
+    day1::Day1, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7,
+    error::AocError,
+};
+
+/// The uniform shape of the solution of a day of the calendar
+pub trait Day {
+    /// Returns the number of the day, from 1 to 25
+    fn number(&self) -> u8;
+
+    /// Returns the answer of the first part for `input` on success
+    ///
+    /// Returns an `AocError` on failure
+    ///
+    /// `input` : the puzzle input
+    fn part1(&self, input: &str) -> Result<u64, AocError>;
+
+    /// Returns the answer of the second part for `input` on success
+    ///
+    /// Returns an `AocError` on failure
+    ///
+    /// `input` : the puzzle input
+    fn part2(&self, input: &str) -> Result<u64, AocError>;
+}
+
+/// The answer of a part of a day, with the time taken to compute it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DayResult {
+    /// The number of the day
+    pub day: u8,
+    /// The part of the day, 1 or 2
+    pub part: u8,
+    /// The answer of the part
+    pub answer: u64,
+    /// The time taken to compute the answer, in microseconds
+    pub micros: u128,
+}
+
+impl DayResult {
+    /// Returns the result as a JSON object, through serde with the `serde` feature
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::day::DayResult;
+    /// let result = DayResult { day: 1, part: 2, answer: 281, micros: 12 };
+    /// assert_eq!(result.to_json(), r#"{"day":1,"part":2,"answer":281,"micros":12}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        #[cfg(feature = "serde")]
+        return serde_json::to_string(self).expect("a DayResult always serializes");
+
+        #[cfg(not(feature = "serde"))]
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"micros":{}}}"#,
+            self.day, self.part, self.answer, self.micros
+        )
+    }
+}
+
+/// Returns every implemented day, in calendar order
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day::all_days;
+/// let days = all_days();
+/// assert_eq!(days[0].number(), 1);
+/// ```
+pub fn all_days() -> Vec<Box<dyn Day>> {
+    vec![
+        Box::new(Day1),
+        Box::new(Day2),
+        Box::new(Day3),
+        Box::new(Day4),
+        Box::new(Day5),
+        Box::new(Day6),
+        Box::new(Day7),
+    ]
+}
+
+/// Returns the location of the puzzle input of `day`
+///
+/// The input folder is given by the `AOC_INPUT_DIR` environment variable, `input` when unset
+///
+/// `day` : the day of the input
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day::input_path;
+/// let path = input_path(7);
+/// assert!(path.ends_with("day7.txt"));
+/// ```
+pub fn input_path(day: u8) -> PathBuf {
+    input_path_in(env::var_os(INPUT_DIR_VAR), day)
+}
+
+const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "input";
+
+fn input_path_in(dir: Option<OsString>, day: u8) -> PathBuf {
+    let dir = dir.map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from);
+
+    dir.join(format!("day{day}.txt"))
+}
+
+/// Returns the puzzle input of `day` read from `input_path(day)` on success
+///
+/// Returns `AocError::MissingInput` if the file does not exist, an `AocError::Io` on other failures
+///
+/// Not available on wasm32 targets, which have no filesystem: solve from a `&str` there
+///
+/// `day` : the day of the input
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read_input(day: u8) -> Result<String, AocError> {
+    read_input_from_path(day, input_path(day))
+}
+
+/// Returns the puzzle input of `day` read from `path` on success
+///
+/// Returns `AocError::MissingInput` if the file does not exist, an `AocError::Io` on other failures
+///
+/// `day` : the day of the input
+///
+/// `path` : the location of the input
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read_input_from_path(day: u8, path: impl AsRef<Path>) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AocError::MissingInput { day },
+        _ => AocError::Io(e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_days() {
+        let days = all_days();
+        for (i, day) in days.iter().enumerate() {
+            assert_eq!(day.number() as usize, i + 1);
+        }
+        assert_eq!(days.len(), 7);
+
+        let day7 = &days[6];
+        assert_eq!(day7.part1("23456 10\nAA234 1").unwrap(), 12);
+        assert!(matches!(day7.part2(""), Err(AocError::Io(_))));
+    }
+
+    use std::path::Path;
+
+    #[test]
+    fn test_input_path_in() {
+        assert_eq!(input_path_in(None, 3), Path::new("input/day3.txt"));
+
+        let dir = OsString::from("/home/elf/.aoc/2023");
+        assert_eq!(
+            input_path_in(Some(dir), 12),
+            Path::new("/home/elf/.aoc/2023/day12.txt")
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_read_input_missing() {
+        let input = read_input_from_path(4, "input/missing.txt");
+        assert!(matches!(input, Err(AocError::MissingInput { day: 4 })));
+    }
+}
@@ -1,5 +1,17 @@
-use core::cmp;
-use std::{fs, io};
+use std::io::{self, BufRead};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(feature = "aho-corasick")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "aho-corasick")]
+use aho_corasick::AhoCorasick;
+#[cfg(feature = "rayon")]
+use rayon::{iter::ParallelIterator, str::ParallelString};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{day::Day, error::AocError};
 
 /// --- Day 1: Trebuchet?! ---
 ///
@@ -44,22 +56,129 @@ use std::{fs, io};
 ///
 /// Returns the sum of all of the calibration values on success
 ///
+/// Returns an `AocError` on failure
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day1_step1() -> Result<u64, AocError> {
+    day1_step1_from_path(day::input_path(1))
+}
+
+/// Returns the sum of all of the calibration values of the document at `path` on success
+///
+/// Returns an `AocError` on failure
+///
+/// `path` : the location of the calibration document
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day1_step1_from_path(path: impl AsRef<Path>) -> Result<u64, AocError> {
+    let input = day::read_input_from_path(1, path)?;
+
+    Ok(day1_step1_str(&input))
+}
+
+/// Returns the sum of all of the calibration values of `input`
+///
+/// With the `rayon` feature, the lines are summed across threads
+///
+/// `input` : the content of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::day1_step1_str;
+/// assert_eq!(day1_step1_str("1abc2\ntreb7uchet"), 12 + 77);
+/// ```
+pub fn day1_step1_str(input: &str) -> u64 {
+    #[cfg(feature = "rayon")]
+    return input.par_lines().filter_map(calibration_value).sum();
+
+    #[cfg(not(feature = "rayon"))]
+    day1_values(input).sum()
+}
+
+/// Returns an iterator over the calibration value of each line of `input`
+///
+/// Lines without any digit, blank ones included, have no value and are skipped
+///
+/// `input` : the content of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::day1_values;
+/// let values: Vec<u64> = day1_values("1abc2\n\ntreb7uchet").collect();
+/// assert_eq!(values, [12, 77]);
+/// ```
+pub fn day1_values<'a>(input: &'a str) -> impl Iterator<Item = u64> + 'a {
+    input.lines().filter_map(calibration_value)
+}
+
+/// Returns the calibration value of the line, its first and last digits forming a two-digit number
+///
+/// Returns `None` if there is no digit
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::calibration_value;
+/// assert_eq!(calibration_value("pqr3stu8vwx"), Some(38));
+/// assert_eq!(calibration_value("treb7uchet"), Some(77));
+/// assert_eq!(calibration_value("   "), None);
+/// ```
+pub fn calibration_value(line: &str) -> Option<u64> {
+    line_value(line.as_bytes())
+}
+
+fn line_value(line: &[u8]) -> Option<u64> {
+    let line = trim_cr(line);
+    let first = first_digit(line)?;
+    let last = last_digit(line)?;
+
+    Some(first * 10 + last)
+}
+
+/// Returns the sum of all of the calibration values read from `reader` on success
+///
 /// Returns an `io::Error` on failure
+///
+/// The document is read line by line, so memory stays constant whatever its size.
+/// Lines are scanned as bytes: invalid UTF-8 is not an error, such bytes are never digits.
+///
+/// `reader` : the source of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
+///
 /// ```
-pub fn day1_step1() -> Result<u64, io::Error> {
-    let input = fs::read_to_string("input/day1.txt")?;
-    let sum = input
-        .lines()
-        .map(|line| {
-            let line = line.as_bytes();
-            let first = first_digit(line).unwrap_or_default();
-            let last = last_digit(line).unwrap_or_default();
+/// # use advent_of_code_2023::day1::day1_step1_buf;
+/// let sum = day1_step1_buf("1abc2\ntreb7uchet".as_bytes()).unwrap();
+/// assert_eq!(sum, 12 + 77);
+/// ```
+pub fn day1_step1_buf<R: BufRead>(reader: R) -> Result<u64, io::Error> {
+    sum_buf(reader, line_value)
+}
 
-            first * 10 + last
-        })
-        .sum::<u64>();
+/// Returns the answers of both parts from a single read of the calibration document on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day1_solve() -> Result<(u64, u64), AocError> {
+    let input = day::read_input(1)?;
 
-    Ok(sum)
+    Ok((day1_step1_str(&input), day1_step2_str(&input)))
+}
+
+/// Returns the line without the `\r` left by a CRLF line ending
+fn trim_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
 }
 
 /// Returns the first digit of the line on success
@@ -114,6 +233,80 @@ pub fn last_digit(line: &[u8]) -> Option<u64> {
     None
 }
 
+/// Returns the first digit of the line on success
+///
+/// Returns `None` if there is no digit
+///
+/// Same as `first_digit`, but the digits are searched with SIMD-accelerated `memchr`,
+/// which pays off on long lines with few digits
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::first_digit_memchr;
+/// let digit = first_digit_memchr("abc4e6gh9jkl".as_bytes());
+/// assert_eq!(digit, Some(4));
+/// ```
+#[cfg(feature = "memchr")]
+pub fn first_digit_memchr(line: &[u8]) -> Option<u64> {
+    // each search only covers the bytes before the best position found so far
+    let mut end = line.len();
+    for [a, b, c] in DIGIT_TRIPLES {
+        if let Some(i) = memchr::memchr3(a, b, c, &line[..end]) {
+            end = i;
+        }
+    }
+    if let Some(i) = memchr::memchr(b'9', &line[..end]) {
+        end = i;
+    }
+
+    line.get(end).and_then(|c| byte_to_u64(*c))
+}
+
+/// Returns the last digit of the line on success
+///
+/// Returns `None` if there is no digit
+///
+/// Same as `last_digit`, but the digits are searched with SIMD-accelerated `memchr`,
+/// which pays off on long lines with few digits
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::last_digit_memchr;
+/// let digit = last_digit_memchr("abc4e6gh9j11l".as_bytes());
+/// assert_eq!(digit, Some(1));
+/// ```
+#[cfg(feature = "memchr")]
+pub fn last_digit_memchr(line: &[u8]) -> Option<u64> {
+    // each search only covers the bytes after the best position found so far
+    let mut found = None;
+    for [a, b, c] in DIGIT_TRIPLES {
+        let start = found.map_or(0, |i| i + 1);
+        if let Some(i) = memchr::memrchr3(a, b, c, &line[start..]) {
+            found = Some(start + i);
+        }
+    }
+    let start = found.map_or(0, |i| i + 1);
+    if let Some(i) = memchr::memrchr(b'9', &line[start..]) {
+        found = Some(start + i);
+    }
+
+    found.and_then(|i| byte_to_u64(line[i]))
+}
+
+/// The digits `0` to `8` grouped for `memchr3`, `9` being searched alone
+#[cfg(feature = "memchr")]
+const DIGIT_TRIPLES: [[u8; 3]; 3] = [*b"012", *b"345", *b"678"];
+
 /// Returns the an optional digit matching this char on success, None if this is not a digit
 ///
 /// `c` : the byte of the char to check
@@ -158,26 +351,125 @@ pub fn byte_to_u64(c: u8) -> Option<u64> {
 ///
 /// Returns the sum of all of the calibration values on success
 ///
-/// Returns an io::Error on failure
+/// Returns an `AocError` on failure
+///
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day1_step2() -> Result<u64, AocError> {
+    day1_step2_from_path(day::input_path(1))
+}
+
+/// Returns the sum of all of the calibration values of the document at `path` on success,
+/// spelled-out digits included
+///
+/// Returns an `AocError` on failure
+///
+/// `path` : the location of the calibration document
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day1_step2_from_path(path: impl AsRef<Path>) -> Result<u64, AocError> {
+    let input = day::read_input_from_path(1, path)?;
+
+    Ok(day1_step2_str(&input))
+}
+
+/// Returns the sum of all of the calibration values of `input`, spelled-out digits included
+///
+/// With the `rayon` feature, the lines are summed across threads
+///
+/// `input` : the content of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::day1_step2_str;
+/// assert_eq!(day1_step2_str("two1nine\nxtwone3four"), 29 + 24);
+/// ```
+pub fn day1_step2_str(input: &str) -> u64 {
+    #[cfg(feature = "rayon")]
+    return input.par_lines().filter_map(named_calibration_value).sum();
+
+    #[cfg(not(feature = "rayon"))]
+    day1_named_values(input).sum()
+}
+
+/// Returns an iterator over the calibration value of each line of `input`, spelled-out digits included
+///
+/// Lines without any digit, blank ones included, have no value and are skipped
+///
+/// `input` : the content of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::day1_named_values;
+/// let values: Vec<u64> = day1_named_values("two1nine\nxtwone3four").collect();
+/// assert_eq!(values, [29, 24]);
+/// ```
+pub fn day1_named_values<'a>(input: &'a str) -> impl Iterator<Item = u64> + 'a {
+    input.lines().filter_map(named_calibration_value)
+}
+
+/// Returns the calibration value of the line, spelled-out digits included
+///
+/// Returns `None` if there is no digit
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::named_calibration_value;
+/// assert_eq!(named_calibration_value("xtwone3four"), Some(24));
+/// assert_eq!(named_calibration_value(""), None);
+/// ```
+pub fn named_calibration_value(line: &str) -> Option<u64> {
+    named_line_value(line.as_bytes())
+}
+
+fn named_line_value(line: &[u8]) -> Option<u64> {
+    let line = trim_cr(line);
+    let first = first_named_digit(line)?;
+    let last = last_named_digit(line)?;
+
+    Some(first * 10 + last)
+}
+
+/// Returns the sum of all of the calibration values read from `reader` on success,
+/// spelled-out digits included
+///
+/// Returns an `io::Error` on failure
+///
+/// See `day1_step1_buf` for the handling of the lines
+///
+/// `reader` : the source of the calibration document
+///
+/// # Examples
+///
+/// Basic usage:
 ///
-pub fn day1_step2() -> Result<u64, io::Error> {
-    let input = fs::read_to_string("input/day1.txt")?;
-    let sum = input
-        .lines()
-        .map(|l| {
-            let line = l.as_bytes();
-            let first = first_named_digit(line).unwrap_or_default();
-            let last = last_named_digit(line).unwrap_or_default();
+/// ```
+/// # use advent_of_code_2023::day1::day1_step2_buf;
+/// let sum = day1_step2_buf("two1nine\nxtwone3four".as_bytes()).unwrap();
+/// assert_eq!(sum, 29 + 24);
+/// ```
+pub fn day1_step2_buf<R: BufRead>(reader: R) -> Result<u64, io::Error> {
+    sum_buf(reader, named_line_value)
+}
 
-            first * 10 + last
-        })
-        .sum::<u64>();
+fn sum_buf<R: BufRead>(reader: R, value: fn(&[u8]) -> Option<u64>) -> Result<u64, io::Error> {
+    let mut sum = 0;
+    for line in reader.split(b'\n') {
+        sum += value(&line?).unwrap_or_default();
+    }
 
     Ok(sum)
 }
 
-const MAX_NAMED_DIGIT_LEN: usize = 5; // three, seven and eight
-const MIN_NAMED_DIGIT_LEN: usize = 3; // one, two and six
 const ONE: [u8; 3] = [b'o', b'n', b'e'];
 const TWO: [u8; 3] = [b't', b'w', b'o'];
 const THREE: [u8; 5] = [b't', b'h', b'r', b'e', b'e'];
@@ -216,51 +508,47 @@ const ALL_DIGITS: [(&[u8], u64); 9] = [
 /// assert_eq!(digit, None);
 /// ```
 pub fn first_named_digit(line: &[u8]) -> Option<u64> {
-    let len = line.len();
-
-    match len.cmp(&MIN_NAMED_DIGIT_LEN) {
-        cmp::Ordering::Less => {
-            let digit = first_digit(line);
-            if digit.is_some() {
-                return digit;
-            }
-        }
-        cmp::Ordering::Equal => {
-            let digit = slice_to_first_u64(line);
-            if digit.is_some() {
-                return digit;
-            }
-        }
-        cmp::Ordering::Greater => {
-            for i in 0..=len - MIN_NAMED_DIGIT_LEN {
-                let end = cmp::min(i + MAX_NAMED_DIGIT_LEN, len);
-                let slice = &line[i..end];
-
-                let digit = slice_to_first_u64(slice);
-                if digit.is_some() {
-                    return digit;
-                }
-            }
-        }
-    }
+    first_named_digit_with(line, &ALL_DIGITS)
+}
 
-    let digit = first_digit(line);
-    if digit.is_some() {
-        return digit;
+/// Returns the first digit of the line on success, digits spelled out with the words of `table` included
+///
+/// Returns `None` if there is no digit
+///
+/// `line` : the line to check
+///
+/// `table` : the spelled-out digits and their values
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::first_named_digit_with;
+/// let table: [(&[u8], u64); 2] = [(b"un", 1), (b"quatre", 4)];
+/// let digit = first_named_digit_with("abquatre2un".as_bytes(), &table);
+/// assert_eq!(digit, Some(4));
+/// ```
+pub fn first_named_digit_with(line: &[u8], table: &[(&[u8], u64)]) -> Option<u64> {
+    for i in 0..line.len() {
+        let digit = slice_to_first_u64(&line[i..], table);
+        if digit.is_some() {
+            return digit;
+        }
     }
 
     None
 }
 
-fn slice_to_first_u64(slice: &[u8]) -> Option<u64> {
+fn slice_to_first_u64(slice: &[u8], table: &[(&[u8], u64)]) -> Option<u64> {
     let digit = byte_to_u64(slice[0]);
     if digit.is_some() {
         return digit;
     }
 
-    for (needle, value) in ALL_DIGITS {
+    for (needle, value) in table {
         if slice.starts_with(needle) {
-            return Some(value);
+            return Some(*value);
         }
     }
 
@@ -284,61 +572,219 @@ fn slice_to_first_u64(slice: &[u8]) -> Option<u64> {
 /// assert_eq!(digit, None);
 /// ```
 pub fn last_named_digit(line: &[u8]) -> Option<u64> {
-    let len = line.len();
-
-    match len.cmp(&MIN_NAMED_DIGIT_LEN) {
-        cmp::Ordering::Less => {
-            let digit = last_digit(line);
-            if digit.is_some() {
-                return digit;
-            }
-        }
-        cmp::Ordering::Equal => {
-            let digit = slice_to_last_u64(line);
-            if digit.is_some() {
-                return digit;
-            }
-        }
-        cmp::Ordering::Greater => {
-            for i in (MIN_NAMED_DIGIT_LEN..=len).rev() {
-                let start = i - cmp::min(i, MAX_NAMED_DIGIT_LEN);
-                let slice = &line[start..i];
-
-                let digit = slice_to_last_u64(slice);
-                if digit.is_some() {
-                    return digit;
-                }
-            }
-        }
-    }
+    last_named_digit_with(line, &ALL_DIGITS)
+}
 
-    if let Some(digit) = last_digit(line) {
-        return Some(digit);
+/// Returns the last digit of the line on success, digits spelled out with the words of `table` included
+///
+/// Returns `None` if there is no digit
+///
+/// `line` : the line to check
+///
+/// `table` : the spelled-out digits and their values
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::last_named_digit_with;
+/// let table: [(&[u8], u64); 3] = [(b"eins", 1), (b"zwei", 2), (b"drei", 3)];
+/// let digit = last_named_digit_with("1abzweixdreiy".as_bytes(), &table);
+/// assert_eq!(digit, Some(3));
+/// ```
+pub fn last_named_digit_with(line: &[u8], table: &[(&[u8], u64)]) -> Option<u64> {
+    for i in (1..=line.len()).rev() {
+        let digit = slice_to_last_u64(&line[..i], table);
+        if digit.is_some() {
+            return digit;
+        }
     }
 
     None
 }
 
-fn slice_to_last_u64(slice: &[u8]) -> Option<u64> {
+fn slice_to_last_u64(slice: &[u8], table: &[(&[u8], u64)]) -> Option<u64> {
     let last = slice.len() - 1;
     let digit = byte_to_u64(slice[last]);
     if digit.is_some() {
         return digit;
     }
 
-    for (needle, value) in ALL_DIGITS {
+    for (needle, value) in table {
         if slice.ends_with(needle) {
-            return Some(value);
+            return Some(*value);
         }
     }
 
     None
 }
 
+/// Returns the first digit of the line on success, spelled-out digits included
+///
+/// Returns `None` if there is no digit
+///
+/// Same as `first_named_digit`, but the line is scanned once by an Aho-Corasick automaton
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::first_named_digit_aho;
+/// let digit = first_named_digit_aho("abc4esixghninejkl".as_bytes());
+/// assert_eq!(digit, Some(4));
+/// ```
+#[cfg(feature = "aho-corasick")]
+pub fn first_named_digit_aho(line: &[u8]) -> Option<u64> {
+    let (matcher, values) = digit_matcher();
+
+    matcher.find(line).map(|m| values[m.pattern().as_usize()])
+}
+
+/// Returns the last digit of the line on success, spelled-out digits included
+///
+/// Returns `None` if there is no digit
+///
+/// Same as `last_named_digit`, but the line is scanned once by an Aho-Corasick automaton
+///
+/// `line` : the line to check
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day1::last_named_digit_aho;
+/// let digit = last_named_digit_aho("abc4e6ghninejelevenl".as_bytes());
+/// assert_eq!(digit, Some(9));
+/// ```
+#[cfg(feature = "aho-corasick")]
+pub fn last_named_digit_aho(line: &[u8]) -> Option<u64> {
+    let (matcher, values) = digit_matcher();
+
+    // no two needles end on the same byte, so the last digit is the match ending last
+    matcher
+        .find_overlapping_iter(line)
+        .max_by_key(|m| m.end())
+        .map(|m| values[m.pattern().as_usize()])
+}
+
+/// Returns the automaton matching plain and spelled-out digits, built once,
+/// and the value of each of its patterns
+#[cfg(feature = "aho-corasick")]
+fn digit_matcher() -> &'static (AhoCorasick, Vec<u64>) {
+    static MATCHER: OnceLock<(AhoCorasick, Vec<u64>)> = OnceLock::new();
+
+    MATCHER.get_or_init(|| {
+        let plain = (b'0'..=b'9').map(|c| (vec![c], (c - b'0') as u64));
+        let named = ALL_DIGITS
+            .iter()
+            .map(|(needle, value)| (needle.to_vec(), *value));
+        let (needles, values): (Vec<_>, Vec<_>) = plain.chain(named).unzip();
+
+        let matcher = AhoCorasick::new(needles).expect("digit needles are valid patterns");
+        (matcher, values)
+    })
+}
+
+/// The solution of day 1, see `day1_step1_str` and `day1_step2_str`
+pub struct Day1;
+
+impl Day for Day1 {
+    fn number(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day1_step1_str(input))
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day1_step2_str(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_day1_values() {
+        assert_eq!(
+            day1_values("1abc2\npqr3stu8vwx").collect::<Vec<_>>(),
+            [12, 38]
+        );
+        assert_eq!(
+            day1_named_values("eightwothree\n7pqrstsixteen").collect::<Vec<_>>(),
+            [83, 76]
+        );
+    }
+
+    #[test]
+    fn test_day1_buf() {
+        let input = "1abc2\ntreb7uchet";
+        assert_eq!(day1_step1_buf(input.as_bytes()).unwrap(), 89);
+        assert_eq!(day1_step1_buf(format!("{input}\n").as_bytes()).unwrap(), 89);
+
+        let input = "two1nine\nxtwone3four";
+        assert_eq!(day1_step2_buf(input.as_bytes()).unwrap(), 53);
+
+        assert_eq!(day1_step1_buf("".as_bytes()).unwrap(), 0);
+
+        let input = [b'1', 0xff, b'2', b'\n', 0xfe, b'7'];
+        assert_eq!(day1_step1_buf(input.as_slice()).unwrap(), 12 + 77);
+    }
+
+    #[test]
+    fn test_calibration_value() {
+        assert_eq!(calibration_value("1abc2"), Some(12));
+        assert_eq!(calibration_value("a7b"), Some(77));
+        assert_eq!(calibration_value(""), None);
+        assert_eq!(calibration_value(" \t "), None);
+        assert_eq!(calibration_value("one"), None);
+
+        assert_eq!(named_calibration_value("one"), Some(11));
+        assert_eq!(named_calibration_value("   "), None);
+    }
+
+    #[test]
+    fn test_day1_blank_lines() {
+        let input = "1abc2\n\n   \ntreb7uchet\n";
+        assert_eq!(day1_step1_str(input), 89);
+        assert_eq!(day1_values(input).count(), 2);
+
+        let input = "two1nine\n\n  \nxtwone3four\n";
+        assert_eq!(day1_step2_str(input), 53);
+        assert_eq!(day1_named_values(input).collect::<Vec<_>>(), [29, 24]);
+    }
+
+    #[test]
+    fn test_day1_crlf() {
+        let lf = "1abc2\npqr3stu8vwx\n";
+        let crlf = "1abc2\r\npqr3stu8vwx\r\n";
+        assert_eq!(day1_step1_str(crlf), day1_step1_str(lf));
+        assert_eq!(day1_step1_buf(crlf.as_bytes()).unwrap(), 50);
+
+        let crlf = "two1nine\r\nzoneight\r";
+        assert_eq!(day1_step2_str(crlf), 29 + 18);
+        assert_eq!(day1_step2_buf(crlf.as_bytes()).unwrap(), 29 + 18);
+        assert_eq!(named_calibration_value("abcone\r"), Some(11));
+    }
+
+    #[test]
+    fn test_day1_str_edge_inputs() {
+        assert_eq!(day1_step1_str(""), 0);
+        assert_eq!(day1_step2_str(""), 0);
+
+        assert_eq!(day1_step1_str("1abc2\ntreb7uchet"), 89);
+        assert_eq!(day1_step1_str("1abc2\ntreb7uchet\n"), 89);
+        assert_eq!(day1_step2_str("two1nine\nxtwone3four"), 53);
+        assert_eq!(day1_step2_str("two1nine\nxtwone3four\n"), 53);
+    }
+
     #[test]
     fn test_first_digit() {
         let digit = first_digit("abc4ef7".as_bytes());
@@ -357,6 +803,69 @@ mod tests {
         assert_eq!(digit, None);
     }
 
+    #[test]
+    fn test_named_digit_with_table() {
+        let french: [(&[u8], u64); 4] = [(b"un", 1), (b"deux", 2), (b"trois", 3), (b"quatre", 4)];
+
+        let digit = first_named_digit_with("xxquatreun".as_bytes(), &french);
+        assert_eq!(digit, Some(4));
+
+        let digit = last_named_digit_with("xxquatreun".as_bytes(), &french);
+        assert_eq!(digit, Some(1));
+
+        let digit = last_named_digit_with("deux7aquatre".as_bytes(), &french);
+        assert_eq!(digit, Some(4));
+
+        let digit = first_named_digit_with("onetwo".as_bytes(), &french);
+        assert_eq!(digit, None);
+    }
+
+    #[cfg(feature = "aho-corasick")]
+    #[test]
+    fn test_named_digit_aho() {
+        let lines = [
+            "abthreede6gh9jkl",
+            "aone",
+            "onea",
+            "81s",
+            "ab4",
+            "fjbbtgone5",
+            "7v",
+            "xiv",
+            "eightwo",
+            "zoneight",
+            "abc4e6gh8jelevenl",
+            "twoa",
+            "abcdef",
+            "",
+        ];
+        for line in lines {
+            let line = line.as_bytes();
+            assert_eq!(first_named_digit_aho(line), first_named_digit(line));
+            assert_eq!(last_named_digit_aho(line), last_named_digit(line));
+        }
+    }
+
+    #[cfg(feature = "memchr")]
+    #[test]
+    fn test_digit_memchr() {
+        let lines = [
+            "abc4ef7",
+            "abcdefghijkl",
+            "9",
+            "0abc",
+            "xyz8",
+            "a1b2c3d4e5f6g7h8i9j0k",
+            "9876543210",
+            "",
+        ];
+        for line in lines {
+            let line = line.as_bytes();
+            assert_eq!(first_digit_memchr(line), first_digit(line));
+            assert_eq!(last_digit_memchr(line), last_digit(line));
+        }
+    }
+
     #[test]
     fn test_byte_to_u64() {
         assert_eq!(byte_to_u64(b'z'), None);
@@ -397,6 +906,12 @@ mod tests {
 
         let digit = first_named_digit("xiv".as_bytes());
         assert_eq!(digit, None);
+
+        let digit = first_named_digit("eightwo".as_bytes());
+        assert_eq!(digit, Some(8));
+
+        let digit = first_named_digit("".as_bytes());
+        assert_eq!(digit, None);
     }
 
     #[test]
@@ -418,5 +933,14 @@ mod tests {
 
         let digit = last_named_digit("abcdef".as_bytes());
         assert_eq!(digit, None);
+
+        let digit = last_named_digit("eightwo".as_bytes());
+        assert_eq!(digit, Some(2));
+
+        let digit = last_named_digit("zoneight".as_bytes());
+        assert_eq!(digit, Some(8));
+
+        let digit = last_named_digit("".as_bytes());
+        assert_eq!(digit, None);
     }
 }
@@ -0,0 +1,442 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{day::Day, error::AocError};
+
+/// The cubes of a game: its id and, for each color, the most cubes seen in a single draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Game {
+    pub id: u64,
+    pub red: u64,
+    pub green: u64,
+    pub blue: u64,
+}
+
+/// The error returned when a line is not a valid game record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameParseError {
+    /// The line does not start with `Game N:`
+    MissingHeader,
+    /// The id of the game is not a number
+    InvalidId(String),
+    /// A draw is not a count followed by `red`, `green` or `blue`
+    InvalidCubes(String),
+}
+
+impl fmt::Display for GameParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameParseError::MissingHeader => write!(f, "missing 'Game N:' header"),
+            GameParseError::InvalidId(id) => write!(f, "invalid game id '{id}'"),
+            GameParseError::InvalidCubes(cubes) => write!(f, "invalid cubes '{cubes}'"),
+        }
+    }
+}
+
+impl Error for GameParseError {}
+
+impl FromStr for Game {
+    type Err = GameParseError;
+
+    /// Parses a game record strictly, unlike `parse_game`
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::day2::{Game, GameParseError};
+    /// let game: Game = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red".parse().unwrap();
+    /// assert_eq!(game, Game { id: 2, red: 1, green: 3, blue: 4 });
+    /// assert_eq!("not a game".parse::<Game>(), Err(GameParseError::MissingHeader));
+    /// ```
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (header, draws) = line.split_once(':').ok_or(GameParseError::MissingHeader)?;
+        let id = header
+            .trim()
+            .strip_prefix("Game")
+            .ok_or(GameParseError::MissingHeader)?
+            .trim();
+        let mut game = Game {
+            id: id
+                .parse()
+                .map_err(|_| GameParseError::InvalidId(id.to_string()))?,
+            ..Game::default()
+        };
+
+        for cubes in draws.split([';', ',']).map(str::trim) {
+            if cubes.is_empty() {
+                continue;
+            }
+
+            let invalid = || GameParseError::InvalidCubes(cubes.to_string());
+            let (count, color) = cubes.split_once(' ').ok_or_else(invalid)?;
+            let count: u64 = count.parse().map_err(|_| invalid())?;
+            let max = match color.trim() {
+                "red" => &mut game.red,
+                "green" => &mut game.green,
+                "blue" => &mut game.blue,
+                _ => return Err(invalid()),
+            };
+            *max = (*max).max(count);
+        }
+
+        Ok(game)
+    }
+}
+
+const MAX_RED: u64 = 12;
+const MAX_GREEN: u64 = 13;
+const MAX_BLUE: u64 = 14;
+
+impl Game {
+    /// Returns whether the game is possible with 12 red, 13 green and 14 blue cubes
+    fn is_possible(&self) -> bool {
+        self.red <= MAX_RED && self.green <= MAX_GREEN && self.blue <= MAX_BLUE
+    }
+
+    /// Returns the power of the minimum set of cubes of the game
+    fn power(&self) -> u64 {
+        self.red * self.green * self.blue
+    }
+}
+
+/// --- Day 2: Cube Conundrum ---
+///
+/// You're launched high into the atmosphere! The apex of your trajectory just barely reaches
+/// the surface of a large island floating in the sky. You gently land in a fluffy pile of leaves.
+/// It's quite cold, but you don't see much snow. An Elf runs over to greet you.
+///
+/// As you walk, the Elf shows you a small bag and some cubes which are either red, green, or blue.
+/// Each time you play this game, he will hide a secret number of cubes of each color in the bag,
+/// and your goal is to figure out information about the number of cubes.
+///
+/// To get information, once a bag has been loaded with cubes, the Elf will reach into the bag,
+/// grab a handful of random cubes, show them to you, and then put them back in the bag.
+/// He'll do this a few times per game.
+///
+/// You play several games and record the information from each game (your puzzle input).
+/// Each game is listed with its ID number followed by a semicolon-separated list
+/// of subsets of cubes that were revealed from the bag.
+///
+/// For example:
+///
+/// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+/// Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+/// Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+/// Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+/// Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+///
+/// The Elf would first like to know which games would have been possible
+/// if the bag contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
+///
+/// In the example above, games 1, 2, and 5 would have been possible.
+/// If you add up the IDs of the games that would have been possible, you get 8.
+///
+/// Determine which games would have been possible if the bag had been loaded with only
+/// 12 red cubes, 13 green cubes, and 14 blue cubes. What is the sum of the IDs of those games?
+///
+/// Returns the sum of the IDs of the possible games on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day2_step1() -> Result<u64, AocError> {
+    let input = day::read_input(2)?;
+
+    day2_step1_str(&input)
+}
+
+/// Returns the sum of the IDs of the games of `input` possible with 12 red, 13 green and 14 blue cubes
+/// on success
+///
+/// Returns an `AocError::Parse` on the first line that is not a valid game
+///
+/// `input` : the record of the games, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day2::day2_step1_str;
+/// let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+///              Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+/// assert_eq!(day2_step1_str(input).unwrap(), 1);
+/// ```
+pub fn day2_step1_str(input: &str) -> Result<u64, AocError> {
+    Ok(games(input)?
+        .iter()
+        .filter(|game| game.is_possible())
+        .map(|game| game.id)
+        .sum())
+}
+
+/// Returns the games of `input` on success, blank lines being skipped
+///
+/// Returns an `AocError::Parse` on the first line that is not a valid game
+fn games(input: &str) -> Result<Vec<Game>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            line.parse().map_err(|e: GameParseError| AocError::Parse {
+                day: 2,
+                line: i + 1,
+                msg: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the game of the line, with the most cubes seen of each color across its draws
+///
+/// Unknown colors and malformed counts are ignored, a missing id is 0; see `Game::from_str`
+/// for a parser reporting them
+///
+/// `line` : the record of the game, as `Game N: 3 blue, 4 red; 1 red, 2 green`
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day2::{parse_game, Game};
+/// let game = parse_game("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red");
+/// assert_eq!(game, Game { id: 4, red: 14, green: 3, blue: 15 });
+/// ```
+pub fn parse_game(line: &str) -> Game {
+    let (header, draws) = line.split_once(':').unwrap_or(("", line));
+    let mut game = Game {
+        id: header
+            .trim()
+            .trim_start_matches("Game")
+            .trim()
+            .parse()
+            .unwrap_or_default(),
+        ..Game::default()
+    };
+
+    for cubes in draws.split([';', ',']) {
+        let Some((count, color)) = cubes.trim().split_once(' ') else {
+            continue;
+        };
+        let Ok(count) = count.parse::<u64>() else {
+            continue;
+        };
+
+        let max = match color.trim() {
+            "red" => &mut game.red,
+            "green" => &mut game.green,
+            "blue" => &mut game.blue,
+            _ => continue,
+        };
+        *max = (*max).max(count);
+    }
+
+    game
+}
+
+/// --- Part Two ---
+///
+/// The Elf says they've stopped producing snow because they aren't getting any water!
+/// He isn't sure why the water stopped; however, he can show you how to get to the water source
+/// to check it out for yourself. It's just up ahead!
+///
+/// As you continue your walk, the Elf poses a second question: in each game you played,
+/// what is the fewest number of cubes of each color that could have been in the bag
+/// to make the game possible?
+///
+/// The power of a set of cubes is equal to the numbers of red, green, and blue cubes multiplied together.
+/// In the example above, the powers of the minimum sets of the five games are 48, 12, 1560, 630, and 36.
+/// Adding up these five powers produces the sum 2286.
+///
+/// For each game, find the minimum set of cubes that must have been present.
+/// What is the sum of the power of these sets?
+///
+/// Returns the sum of the powers of the minimum sets on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day2_step2() -> Result<u64, AocError> {
+    let input = day::read_input(2)?;
+
+    day2_step2_str(&input)
+}
+
+/// Returns the sum of the powers of the minimum sets of cubes of the games of `input` on success
+///
+/// Returns an `AocError::Parse` on the first line that is not a valid game
+///
+/// `input` : the record of the games, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day2::day2_step2_str;
+/// let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+/// assert_eq!(day2_step2_str(input).unwrap(), 48);
+/// ```
+pub fn day2_step2_str(input: &str) -> Result<u64, AocError> {
+    Ok(games(input)?.iter().map(Game::power).sum())
+}
+
+/// Returns the sum of the IDs of the possible games of `input` and the sum of the powers
+/// of their minimum sets of cubes on success, in one pass over the games
+///
+/// Returns an `AocError::Parse` on the first line that is not a valid game
+///
+/// `input` : the record of the games, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day2::day2_both;
+/// let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+/// assert_eq!(day2_both(input).unwrap(), (1, 48));
+/// ```
+pub fn day2_both(input: &str) -> Result<(u64, u64), AocError> {
+    Ok(games(input)?.iter().fold((0, 0), |(ids, powers), game| {
+        let id = if game.is_possible() { game.id } else { 0 };
+        (ids + id, powers + game.power())
+    }))
+}
+
+/// The solution of day 2, see `day2_step1_str` and `day2_step2_str`
+pub struct Day2;
+
+impl Day for Day2 {
+    fn number(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        day2_step1_str(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        day2_step2_str(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day2_step1_str() {
+        assert_eq!(day2_step1_str("").unwrap(), 0);
+        assert_eq!(day2_step1_str("\nGame 3: 1 red\n  \n").unwrap(), 3);
+
+        let Err(AocError::Parse { day, line, msg }) =
+            day2_step1_str("Game 1: 1 red\nGame 2: 1 pink")
+        else {
+            panic!("an unknown color is an error");
+        };
+        assert_eq!((day, line, msg.as_str()), (2, 2, "invalid cubes '1 pink'"));
+    }
+
+    #[test]
+    fn test_day2_step2_str() {
+        assert_eq!(day2_step2_str("").unwrap(), 0);
+        assert!(day2_step2_str("Game x: 1 red").is_err());
+    }
+
+    #[test]
+    fn test_day2_both() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 20 red, 1 green, 1 blue";
+        assert_eq!(
+            day2_both(input).unwrap(),
+            (
+                day2_step1_str(input).unwrap(),
+                day2_step2_str(input).unwrap()
+            )
+        );
+        assert_eq!(day2_both("").unwrap(), (0, 0));
+        assert!(day2_both("not a game").is_err());
+    }
+
+    #[test]
+    fn test_game_from_str() {
+        assert_eq!(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green".parse(),
+            Ok(Game {
+                id: 1,
+                red: 4,
+                green: 2,
+                blue: 6
+            })
+        );
+        assert_eq!(
+            "Game 13: ".parse(),
+            Ok(Game {
+                id: 13,
+                ..Game::default()
+            })
+        );
+
+        assert_eq!(
+            "not a game".parse::<Game>(),
+            Err(GameParseError::MissingHeader)
+        );
+        assert_eq!(
+            "Round 1: 1 red".parse::<Game>(),
+            Err(GameParseError::MissingHeader)
+        );
+        assert_eq!(
+            "Game one: 1 red".parse::<Game>(),
+            Err(GameParseError::InvalidId("one".to_string()))
+        );
+        assert_eq!(
+            "Game 1: red".parse::<Game>(),
+            Err(GameParseError::InvalidCubes("red".to_string()))
+        );
+        assert_eq!(
+            "Game 1: x red".parse::<Game>(),
+            Err(GameParseError::InvalidCubes("x red".to_string()))
+        );
+        assert_eq!(
+            "Game 12: 5 purple, 2 red".parse::<Game>(),
+            Err(GameParseError::InvalidCubes("5 purple".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let game = parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        assert_eq!(
+            game,
+            Game {
+                id: 1,
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        );
+
+        let game = parse_game("Game 12: 5 purple, 2 red");
+        assert_eq!(
+            game,
+            Game {
+                id: 12,
+                red: 2,
+                green: 0,
+                blue: 0
+            }
+        );
+
+        let game = parse_game("Game 13: ");
+        assert_eq!(
+            game,
+            Game {
+                id: 13,
+                ..Game::default()
+            }
+        );
+    }
+}
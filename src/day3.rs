@@ -0,0 +1,333 @@
+use std::{collections::HashMap, iter};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{day::Day, error::AocError, grid::Grid};
+
+/// A number of the schematic and the cells it spans, `col_start..col_end` on `row`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NumberSpan {
+    value: u64,
+    row: usize,
+    col_start: usize,
+    col_end: usize,
+}
+
+/// --- Day 3: Gear Ratios ---
+///
+/// You and the Elf eventually reach a gondola lift station; he says the gondola lift
+/// will take you up to the water source, but this is as far as he can bring you. You go inside.
+///
+/// It doesn't take long to find the gondolas, but there seems to be a problem: they're not moving.
+/// An engineer explains that an engine part seems to be missing from the engine,
+/// but nobody can figure out which one. If you can add up all the part numbers
+/// in the engine schematic, it should be easy to work out which part is missing.
+///
+/// The engine schematic (your puzzle input) consists of a visual representation of the engine.
+/// There are lots of numbers and symbols you don't really understand,
+/// but apparently any number adjacent to a symbol, even diagonally, is a "part number"
+/// and should be included in your sum. (Periods (.) do not count as a symbol.)
+///
+/// Here is an example engine schematic:
+///
+/// 467..114..
+/// ...*......
+/// ..35..633.
+/// ......#...
+/// 617*......
+/// .....+.58.
+/// ..592.....
+/// ......755.
+/// ...$.*....
+/// .664.598..
+///
+/// In this schematic, two numbers are not part numbers because they are not adjacent to a symbol:
+/// 114 (top right) and 58 (middle right). Every other number is adjacent to a symbol
+/// and so is a part number; their sum is 4361.
+///
+/// What is the sum of all of the part numbers in the engine schematic?
+///
+/// Returns the sum of all of the part numbers on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day3_step1() -> Result<u64, AocError> {
+    let input = day::read_input(3)?;
+
+    Ok(day3_step1_str(&input))
+}
+
+/// Returns the sum of all of the part numbers of the schematic `input`
+///
+/// Rows shorter than the longest one are padded with `.`
+///
+/// `input` : the engine schematic
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day3::day3_step1_str;
+/// assert_eq!(day3_step1_str("467..114..\n...*......"), 467);
+/// ```
+pub fn day3_step1_str(input: &str) -> u64 {
+    let grid = schematic(input);
+
+    part_number_sum(&grid, &numbers_in_grid(&grid))
+}
+
+/// --- Part Two ---
+///
+/// The engineer finds the missing part and installs it in the engine!
+/// As the engine springs to life, you jump in the closest gondola,
+/// finally ready to ascend to the water source.
+///
+/// You don't seem to be going very fast, though. Maybe something is still wrong?
+/// The missing part wasn't the only issue - one of the gears in the engine is wrong.
+/// A gear is any * symbol that is adjacent to exactly two part numbers.
+/// Its gear ratio is the result of multiplying those two numbers together.
+///
+/// This time, you need to find the gear ratio of every gear and add them all up
+/// so that the engineer can figure out which gear needs to be replaced.
+///
+/// In the example above, there are two gears. The first is in the top left;
+/// it has part numbers 467 and 35, so its gear ratio is 16345.
+/// The second gear is in the lower right; its gear ratio is 451490.
+/// (The * adjacent to 617 is not a gear because it is only adjacent to one part number.)
+/// Adding up all of the gear ratios produces 467835.
+///
+/// What is the sum of all of the gear ratios in your engine schematic?
+///
+/// Returns the sum of all of the gear ratios on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day3_step2() -> Result<u64, AocError> {
+    let input = day::read_input(3)?;
+
+    Ok(day3_step2_str(&input))
+}
+
+/// Returns the sum of all of the gear ratios of the schematic `input`
+///
+/// Rows shorter than the longest one are padded with `.`
+///
+/// `input` : the engine schematic
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day3::day3_step2_str;
+/// assert_eq!(day3_step2_str("467..\n...*.\n..35."), 467 * 35);
+/// ```
+pub fn day3_step2_str(input: &str) -> u64 {
+    let grid = schematic(input);
+
+    gear_ratio_sum(&grid, &numbers_in_grid(&grid))
+}
+
+/// Returns the sum of the part numbers and the sum of the gear ratios of the schematic `input`,
+/// parsing the grid and its numbers once for both
+///
+/// `input` : the engine schematic
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day3::day3_both;
+/// assert_eq!(day3_both("467..\n...*.\n..35."), (467 + 35, 467 * 35));
+/// ```
+pub fn day3_both(input: &str) -> (u64, u64) {
+    let grid = schematic(input);
+    let numbers = numbers_in_grid(&grid);
+
+    (
+        part_number_sum(&grid, &numbers),
+        gear_ratio_sum(&grid, &numbers),
+    )
+}
+
+/// Returns the sum of the numbers of the grid adjacent to a symbol
+fn part_number_sum(grid: &Grid, numbers: &[NumberSpan]) -> u64 {
+    numbers
+        .iter()
+        .filter(|number| {
+            span_neighbors(grid, number).any(|(row, col)| grid.get(row, col).is_some_and(is_symbol))
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+/// Returns the sum of the products of the two numbers of the grid around each `*` touching exactly two
+fn gear_ratio_sum(grid: &Grid, numbers: &[NumberSpan]) -> u64 {
+    // the numbers adjacent to each `*`, by their index so that a number touching
+    // the same `*` from several of its cells is only counted once
+    let mut gears: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, number) in numbers.iter().enumerate() {
+        for cell in span_neighbors(grid, number) {
+            if grid.get(cell.0, cell.1) != Some(b'*') {
+                continue;
+            }
+
+            let adjacent = gears.entry(cell).or_default();
+            if adjacent.last() != Some(&i) {
+                adjacent.push(i);
+            }
+        }
+    }
+
+    gears
+        .values()
+        .filter(|adjacent| adjacent.len() == 2)
+        .map(|adjacent| numbers[adjacent[0]].value * numbers[adjacent[1]].value)
+        .sum()
+}
+
+/// The solution of day 3, see `day3_step1_str` and `day3_step2_str`
+pub struct Day3;
+
+impl Day for Day3 {
+    fn number(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day3_step1_str(input))
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day3_step2_str(input))
+    }
+}
+
+/// Returns whether the cell is a symbol, anything but a digit or a `.`
+fn is_symbol(cell: u8) -> bool {
+    cell != b'.' && !cell.is_ascii_digit()
+}
+
+/// Returns the schematic as a grid, rows shorter than the longest one padded with `.`
+///
+/// Widths are counted in bytes, as the grid's are, so that non-ASCII rows get padded too
+fn schematic(input: &str) -> Grid {
+    let width = input.lines().map(str::len).max().unwrap_or_default();
+    let mut padded = String::with_capacity((width + 1) * input.lines().count());
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        padded.push_str(line);
+        padded.extend(iter::repeat_n('.', width - line.len()));
+        padded.push('\n');
+    }
+
+    padded
+        .parse()
+        .expect("rows are padded to the same byte width")
+}
+
+/// Returns the numbers of the grid, in row-major order
+fn numbers_in_grid(grid: &Grid) -> Vec<NumberSpan> {
+    let mut numbers = Vec::new();
+
+    for row in 0..grid.height() {
+        let mut col = 0;
+        while col < grid.width() {
+            let col_start = col;
+            let mut value = 0;
+            while let Some(digit) = grid.get(row, col).filter(u8::is_ascii_digit) {
+                value = value * 10 + (digit - b'0') as u64;
+                col += 1;
+            }
+
+            if col > col_start {
+                numbers.push(NumberSpan {
+                    value,
+                    row,
+                    col_start,
+                    col_end: col,
+                });
+            } else {
+                col += 1;
+            }
+        }
+    }
+
+    numbers
+}
+
+/// Returns the cells around the number, diagonals included, some possibly more than once
+fn span_neighbors<'a>(
+    grid: &'a Grid,
+    number: &NumberSpan,
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let row = number.row;
+    (number.col_start..number.col_end).flat_map(move |col| grid.neighbors8(row, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day3_step1_str() {
+        assert_eq!(day3_step1_str(""), 0);
+    }
+
+    #[test]
+    fn test_day3_step1_edges() {
+        // numbers touching the grid border
+        assert_eq!(day3_step1_str("12..\n...#"), 0);
+        assert_eq!(day3_step1_str("12\n.#"), 12);
+        assert_eq!(day3_step1_str("#.\n.9"), 9);
+
+        // a symbol above the middle digit counts the whole number, once
+        assert_eq!(day3_step1_str(".#.\n123"), 123);
+        assert_eq!(day3_step1_str("123\n.#."), 123);
+
+        // a single symbol between two numbers of the same row counts both
+        assert_eq!(day3_step1_str("..12*34.."), 46);
+
+        // ragged rows
+        assert_eq!(day3_step1_str("5\n.+"), 5);
+        // non-ASCII cells are symbols, the rows being padded by bytes
+        assert_eq!(day3_step1_str("é1\n.#"), 1);
+        assert_eq!(day3_step1_str("..7\n.\né"), 0);
+    }
+
+    #[test]
+    fn test_day3_step2_str() {
+        // a `*` touched by the same number from several cells is not a gear
+        assert_eq!(day3_step2_str("123\n.*."), 0);
+        // three numbers around a `*` do not make a gear
+        assert_eq!(day3_step2_str("1.2\n.*.\n..3"), 0);
+        assert_eq!(day3_step2_str("10*20"), 200);
+    }
+
+    #[test]
+    fn test_day3_both() {
+        assert_eq!(day3_both(""), (0, 0));
+        for input in ["12\n.#", "10*20", "1.2\n.*.\n..3", "467..\n...*.\n..35."] {
+            assert_eq!(
+                day3_both(input),
+                (day3_step1_str(input), day3_step2_str(input))
+            );
+        }
+    }
+
+    #[test]
+    fn test_numbers_in_grid() {
+        let grid: Grid = "467..\n..*..\n".parse().unwrap();
+        assert_eq!(
+            numbers_in_grid(&grid),
+            [NumberSpan {
+                value: 467,
+                row: 0,
+                col_start: 0,
+                col_end: 3
+            }]
+        );
+        assert_eq!(numbers_in_grid(&"...\n".parse().unwrap()), []);
+    }
+}
@@ -0,0 +1,233 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{day::Day, error::AocError, parse};
+
+/// --- Day 4: Scratchcards ---
+///
+/// The gondola takes you up. Strangely, though, the ground doesn't seem to be coming with you;
+/// you're not climbing a mountain. As the circle of Snow Island recedes below you,
+/// an entire new landmass suddenly appears above you!
+///
+/// As you exit the gondola, an Elf is sitting on the ground next to a pile of colorful cards.
+/// There, you discover dozens of scratchcards, all with their opaque covering already scratched off.
+/// Picking one up, it looks like each card has two lists of numbers separated by a vertical bar (|):
+/// a list of winning numbers and then a list of numbers you have.
+/// You organize the information into a table (your puzzle input).
+///
+/// As far as the Elf has been able to figure out, you have to figure out which of the numbers
+/// you have appear in the list of winning numbers. The first match makes the card worth one point
+/// and each match after the first doubles the point value of that card.
+///
+/// For example:
+///
+/// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+/// Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+/// Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+/// Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+/// Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+/// Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+///
+/// In the above example, card 1 has four winning numbers (48, 83, 17, and 86), so it is worth 8 points.
+/// Card 2 is worth 2 points, card 3 is worth 2 points, card 4 is worth 1 point,
+/// and cards 5 and 6 are worth no points. So, in this example,
+/// the Elf's pile of scratchcards is worth 13 points.
+///
+/// Take a seat in the large pile of colorful cards. How many points are they worth in total?
+///
+/// Returns the total points of the scratchcards on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day4_step1() -> Result<u64, AocError> {
+    let input = day::read_input(4)?;
+
+    Ok(day4_step1_str(&input))
+}
+
+/// Returns the total points of the scratchcards of `input`
+///
+/// `input` : the scratchcards, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day4::day4_step1_str;
+/// assert_eq!(day4_step1_str("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"), 8);
+/// ```
+pub fn day4_step1_str(input: &str) -> u64 {
+    day4_match_counts(input).into_iter().map(card_score).sum()
+}
+
+/// --- Part Two ---
+///
+/// Just as you're about to report your findings to the Elf, one of you realizes that the rules
+/// have actually been printed on the back of every card this whole time.
+///
+/// There's no such thing as "points". Instead, scratchcards only cause you to win more scratchcards
+/// equal to the number of winning numbers you have.
+///
+/// Specifically, you win copies of the scratchcards below the winning card equal to
+/// the number of matches. So, if card 10 were to have 5 matching numbers,
+/// you would win one copy each of cards 11, 12, 13, 14, and 15.
+///
+/// Copies of scratchcards are scored like normal scratchcards and have the same card number
+/// as the card they copied. (Cards will never make you copy a card past the end of the table.)
+///
+/// In the example above, once all of the originals and copies have been processed,
+/// you end up with 1 instance of card 1, 2 instances of card 2, 4 instances of card 3,
+/// 8 instances of card 4, 14 instances of card 5, and 1 instance of card 6.
+/// In total, this example pile of scratchcards causes you to ultimately have 30 scratchcards!
+///
+/// Process all of the original and copied scratchcards until no more scratchcards are won.
+/// Including the original set of scratchcards, how many total scratchcards do you end up with?
+///
+/// Returns the total number of scratchcards on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day4_step2() -> Result<u64, AocError> {
+    let input = day::read_input(4)?;
+
+    Ok(day4_step2_str(&input))
+}
+
+/// Returns the total number of scratchcards held once the copies won by the cards of `input` are processed
+///
+/// `input` : the scratchcards, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day4::day4_step2_str;
+/// let input = "Card 1: 41 48 | 41 48\nCard 2: 1 | 2\nCard 3: 1 | 2";
+/// assert_eq!(day4_step2_str(input), 1 + 2 + 2);
+/// ```
+pub fn day4_step2_str(input: &str) -> u64 {
+    let matches = day4_match_counts(input);
+    let mut counts = vec![1; matches.len()];
+
+    for (i, card_matches) in matches.iter().enumerate() {
+        // copies never run past the last card
+        let last = (i + *card_matches as usize).min(matches.len() - 1);
+        for j in i + 1..=last {
+            counts[j] += counts[i];
+        }
+    }
+
+    counts.iter().sum()
+}
+
+/// The solution of day 4, see `day4_step1_str` and `day4_step2_str`
+pub struct Day4;
+
+impl Day for Day4 {
+    fn number(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day4_step1_str(input))
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        Ok(day4_step2_str(input))
+    }
+}
+
+/// Returns how many winning numbers each scratchcard of `input` has, in order
+///
+/// `input` : the scratchcards, one per line, blank lines being skipped
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day4::day4_match_counts;
+/// let input = "Card 1: 41 48 | 41 48\n\nCard 2: 1 | 2";
+/// assert_eq!(day4_match_counts(input), [2, 0]);
+/// ```
+pub fn day4_match_counts(input: &str) -> Vec<u64> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(card_matches)
+        .collect()
+}
+
+/// Returns the points of a card with `matches` winning numbers: 1 for the first, doubled for each other
+fn card_score(matches: u64) -> u64 {
+    match matches {
+        0 => 0,
+        n => 1 << (n - 1),
+    }
+}
+
+/// Returns how many of the numbers you have on the card are winning numbers
+///
+/// `line` : the card, as `Card N: winning numbers | numbers you have`
+fn card_matches(line: &str) -> u64 {
+    let (_, numbers) = line.split_once(':').unwrap_or(("", line));
+    let Some((winning, yours)) = numbers.split_once('|') else {
+        return 0;
+    };
+
+    let winning = parse::uints(winning);
+    parse::uints(yours)
+        .iter()
+        .filter(|number| winning.contains(number))
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day4_step1_str() {
+        assert_eq!(day4_step1_str(""), 0);
+    }
+
+    #[test]
+    fn test_day4_step2_str() {
+        assert_eq!(day4_step2_str(""), 0);
+
+        // the last card's matches would copy past the end of the table
+        assert_eq!(day4_step2_str("Card 1: 1 | 2\nCard 2: 5 6 | 5 6"), 2);
+    }
+
+    #[test]
+    fn test_day4_match_counts() {
+        assert!(day4_match_counts("").is_empty());
+        assert_eq!(
+            day4_match_counts("Card 1: 1 2 | 2 1\n  \nCard 2: 3 | 4\n"),
+            [2, 0]
+        );
+    }
+
+    #[test]
+    fn test_card_score() {
+        assert_eq!(card_score(0), 0);
+        assert_eq!(card_score(1), 1);
+        assert_eq!(card_score(2), 2);
+        // the first card of the example
+        assert_eq!(card_score(4), 8);
+    }
+
+    #[test]
+    fn test_card_matches() {
+        assert_eq!(
+            card_matches("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
+            4
+        );
+        assert_eq!(
+            card_matches("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36"),
+            0
+        );
+        assert_eq!(card_matches("Card 7: 1 2 3"), 0);
+    }
+}
@@ -0,0 +1,450 @@
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{
+    day::Day,
+    error::AocError,
+    parse,
+    ranges::{self, Range},
+};
+
+/// A map of the almanac: its ranges, as `(destination start, source start, length)`
+type Map = Vec<(u64, u64, u64)>;
+
+/// The seeds to plant and the chain of maps from seeds to locations, in order
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Almanac {
+    seeds: Vec<u64>,
+    /// The line of the seeds, 1-based
+    seeds_line: usize,
+    maps: Vec<Map>,
+}
+
+/// --- Day 5: If You Give A Seed A Fertilizer ---
+///
+/// You take the boat and find the gardener right where you were told he would be:
+/// managing a giant "garden" that looks more to you like a farm.
+///
+/// The almanac (your puzzle input) lists all of the seeds that need to be planted.
+/// It also lists what type of soil to use with each kind of seed, what type of fertilizer
+/// to use with each kind of soil, what type of water to use with each kind of fertilizer, and so on.
+/// Every type of seed, soil, fertilizer and so on is identified with a number,
+/// but numbers are reused by each category.
+///
+/// The almanac starts by listing which seeds need to be planted: seeds 79, 14, 55, and 13.
+///
+/// The rest of the almanac contains a list of maps which describe how to convert numbers
+/// from a source category into numbers in a destination category.
+/// Rather than list every source number and its corresponding destination number one by one,
+/// the maps describe entire ranges of numbers that can be converted.
+/// Each line within a map contains three numbers: the destination range start,
+/// the source range start, and the range length.
+/// Any source numbers that aren't mapped correspond to the same destination number.
+///
+/// For example:
+///
+/// seeds: 79 14 55 13
+///
+/// seed-to-soil map:
+/// 50 98 2
+/// 52 50 48
+///
+/// (and so on for soil-to-fertilizer, fertilizer-to-water, water-to-light,
+/// light-to-temperature, temperature-to-humidity and humidity-to-location)
+///
+/// The gardener and his team want to get started as soon as possible,
+/// so they'd like to know the closest location that needs a seed.
+/// In the example, the lowest location number is 35.
+///
+/// What is the lowest location number that corresponds to any of the initial seed numbers?
+///
+/// Returns the lowest location number on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day5_step1() -> Result<u64, AocError> {
+    let input = day::read_input(5)?;
+
+    day5_step1_str(&input)
+}
+
+/// Returns the lowest location of the seeds of the almanac `input` on success
+///
+/// Returns an `AocError::Parse` if the almanac has no seeds
+///
+/// `input` : the almanac
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day5::day5_step1_str;
+/// let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+/// assert_eq!(day5_step1_str(input).unwrap(), 14);
+/// assert!(day5_step1_str("seeds:\n").is_err());
+/// ```
+pub fn day5_step1_str(input: &str) -> Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.maps.iter().fold(*seed, map_value))
+        .min()
+        .ok_or_else(|| parse_error(almanac.seeds_line, "no seeds"))
+}
+
+/// Returns the lowest location of the seeds of the almanac `input` on success, like `day5_step1_str`
+/// but mapping the seeds across threads
+///
+/// Returns an `AocError::Parse` if the almanac has no seeds
+///
+/// The seeds are mapped independently, but it only pays off for much longer seed lists
+/// than the puzzle's, where threading costs more than it saves
+///
+/// `input` : the almanac
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day5::day5_step1_par;
+/// let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+/// assert_eq!(day5_step1_par(input).unwrap(), 14);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn day5_step1_par(input: &str) -> Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+
+    almanac
+        .seeds
+        .par_iter()
+        .map(|seed| almanac.maps.iter().fold(*seed, map_value))
+        .min()
+        .ok_or_else(|| parse_error(almanac.seeds_line, "no seeds"))
+}
+
+/// --- Part Two ---
+///
+/// Everyone will starve if you only plant such a small number of seeds.
+/// Re-reading the almanac, it looks like the seeds: line actually describes ranges of seed numbers.
+///
+/// The values on the initial seeds: line come in pairs. Within each pair, the first value
+/// is the start of the range and the second value is the length of the range.
+/// So, in the first line of the example above:
+///
+/// seeds: 79 14 55 13
+///
+/// This line describes two ranges of seed numbers to be planted in the garden.
+/// The first range starts with seed number 79 and contains 14 values: 79, 80, ..., 91, 92.
+/// The second range starts with seed number 55 and contains 13 values: 55, 56, ..., 66, 67.
+///
+/// Now, rather than considering four seed numbers, you need to consider a total of 27 seed numbers.
+///
+/// In the above example, the lowest location number can be obtained from seed number 82,
+/// which corresponds to location 46.
+///
+/// Consider all of the initial seed numbers listed in the ranges on the first line of the almanac.
+/// What is the lowest location number that corresponds to any of the initial seed numbers?
+///
+/// Returns the lowest location number on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day5_step2() -> Result<u64, AocError> {
+    let input = day::read_input(5)?;
+
+    day5_step2_str(&input)
+}
+
+/// Returns the lowest location of the seed ranges of the almanac `input` on success
+///
+/// Returns an `AocError::Parse` if the almanac has no seeds or an odd number of seed values
+///
+/// The seeds are read as `(start, length)` pairs, too many to map one by one,
+/// so whole ranges are mapped through each map instead
+///
+/// `input` : the almanac
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day5::day5_step2_str;
+/// let input = "seeds: 48 4\n\nseed-to-soil map:\n52 50 48\n";
+/// assert_eq!(day5_step2_str(input).unwrap(), 48);
+/// assert!(day5_step2_str("seeds: 48 4 52\n").is_err());
+/// ```
+pub fn day5_step2_str(input: &str) -> Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+    let stages = range_stages(&almanac)?;
+
+    stages
+        .last()
+        .into_iter()
+        .flatten()
+        .map(|range| range.start as u64)
+        .min()
+        .ok_or_else(|| parse_error(almanac.seeds_line, "no seeds"))
+}
+
+/// Returns the seed ranges of the almanac `input` then the ranges they cover after each map,
+/// as inclusive `(start, end)` intervals, on success
+///
+/// Returns an `AocError::Parse` if the almanac has no seeds or an odd number of seed values
+///
+/// The last stage holds the location ranges, whose lowest start is the answer of `day5_step2_str`
+///
+/// `input` : the almanac
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day5::day5_step2_trace;
+/// let input = "seeds: 48 4\n\nseed-to-soil map:\n52 50 48\n";
+/// assert_eq!(day5_step2_trace(input).unwrap(), [vec![(48, 51)], vec![(48, 49), (52, 53)]]);
+/// ```
+pub fn day5_step2_trace(input: &str) -> Result<Vec<Vec<(u64, u64)>>, AocError> {
+    let almanac = parse_almanac(input)?;
+
+    Ok(range_stages(&almanac)?
+        .iter()
+        .map(|ranges| {
+            ranges
+                .iter()
+                .map(|range| (range.start as u64, range.end as u64))
+                .collect()
+        })
+        .collect())
+}
+
+/// Returns the seed ranges of the almanac then the ranges they cover after each map, on success
+///
+/// Returns an `AocError::Parse` if the seed values are not `(start, length)` pairs
+fn range_stages(almanac: &Almanac) -> Result<Vec<Vec<Range>>, AocError> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(parse_error(
+            almanac.seeds_line,
+            "seeds are not (start, length) pairs",
+        ));
+    }
+
+    let seeds: Vec<Range> = almanac
+        .seeds
+        .chunks_exact(2)
+        .filter(|pair| pair[1] > 0)
+        .map(|pair| Range::new(pair[0] as i64, (pair[0] + pair[1] - 1) as i64))
+        .collect();
+
+    let mut stages = vec![seeds];
+    for map in &almanac.maps {
+        let ranges = stages.last().cloned().unwrap_or_default();
+        stages.push(map_ranges(ranges, map));
+    }
+
+    Ok(stages)
+}
+
+/// Returns the destinations of `ranges` through the map, merged
+///
+/// Each range is split against the source ranges of the map: the covered parts are shifted
+/// to their destination and what no source range covers is kept as is
+fn map_ranges(ranges: Vec<Range>, map: &Map) -> Vec<Range> {
+    let mut unmapped = ranges;
+    let mut mapped = Vec::new();
+
+    for (dest, src, len) in map.iter().filter(|(_, _, len)| *len > 0) {
+        let source = Range::new(*src as i64, (src + len - 1) as i64);
+        let shift = *dest as i64 - *src as i64;
+
+        let mut rest = Vec::with_capacity(unmapped.len());
+        for range in &unmapped {
+            if let Some(common) = range.intersect(&source) {
+                mapped.push(Range::new(common.start + shift, common.end + shift));
+            }
+            rest.extend(range.subtract(&source));
+        }
+        unmapped = rest;
+    }
+
+    mapped.append(&mut unmapped);
+    ranges::merge(&mut mapped);
+
+    mapped
+}
+
+/// The solution of day 5, see `day5_step1_str` and `day5_step2_str`
+pub struct Day5;
+
+impl Day for Day5 {
+    fn number(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        day5_step1_str(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        day5_step2_str(input)
+    }
+}
+
+/// Returns the almanac of `input`, its maps in order of appearance, on success
+///
+/// Returns an `AocError::Parse` if there is no seed
+///
+/// Lines that are neither the seeds, a map header nor a range are ignored
+fn parse_almanac(input: &str) -> Result<Almanac, AocError> {
+    let mut almanac = Almanac::default();
+
+    for (i, line) in input.lines().map(str::trim).enumerate() {
+        if let Some(seeds) = line.strip_prefix("seeds:") {
+            almanac.seeds = parse::uints(seeds);
+            almanac.seeds_line = i + 1;
+        } else if line.ends_with("map:") {
+            almanac.maps.push(Map::new());
+        } else if let (Some(map), [dest, src, len]) =
+            (almanac.maps.last_mut(), parse::uints(line).as_slice())
+        {
+            map.push((*dest, *src, *len));
+        }
+    }
+
+    if almanac.seeds.is_empty() {
+        // a missing seeds line is reported where it was expected, on the first line
+        return Err(parse_error(almanac.seeds_line.max(1), "no seeds"));
+    }
+
+    Ok(almanac)
+}
+
+/// Returns the parse error of the almanac at `line`
+fn parse_error(line: usize, msg: &str) -> AocError {
+    AocError::Parse {
+        day: 5,
+        line,
+        msg: msg.to_string(),
+    }
+}
+
+/// Returns the destination of `value` through the map, `value` itself if no range covers it
+fn map_value(value: u64, map: &Map) -> u64 {
+    map.iter()
+        .find(|(_, src, len)| value >= *src && value - src < *len)
+        .map_or(value, |(dest, src, _)| dest + (value - src))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_seeds() {
+        for input in ["", "seeds:\n\nseed-to-soil map:\n50 98 2\n", "\nseeds:  \n"] {
+            assert!(matches!(
+                day5_step1_str(input),
+                Err(AocError::Parse { day: 5, .. })
+            ));
+            assert!(matches!(
+                day5_step2_str(input),
+                Err(AocError::Parse { day: 5, .. })
+            ));
+        }
+
+        let Err(AocError::Parse { line, msg, .. }) = day5_step1_str("\nseeds:\n") else {
+            panic!("an empty seeds line is an error");
+        };
+        assert_eq!((line, msg.as_str()), (2, "no seeds"));
+
+        // only empty seed ranges
+        assert!(day5_step2_str("seeds: 79 0\n").is_err());
+    }
+
+    #[test]
+    fn test_day5_step2_trace() {
+        let input =
+            "seeds: 45 10\n\nseed-to-soil map:\n52 50 48\n\nsoil-to-fertilizer map:\n0 52 3\n";
+        assert_eq!(
+            day5_step2_trace(input).unwrap(),
+            [
+                vec![(45, 54)],
+                vec![(45, 49), (52, 56)],
+                vec![(0, 2), (45, 49), (55, 56)]
+            ]
+        );
+        assert_eq!(day5_step2_trace("seeds: 7 1\n").unwrap(), [vec![(7, 7)]]);
+        assert!(day5_step2_trace("seeds: 7\n").is_err());
+    }
+
+    #[test]
+    fn test_day5_step2_odd_seeds() {
+        assert!(day5_step1_str("seeds: 79 14 55\n").is_ok());
+        assert!(matches!(
+            day5_step2_str("seeds: 79 14 55\n"),
+            Err(AocError::Parse {
+                day: 5,
+                line: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        let map = vec![(50, 98, 2), (52, 50, 48)];
+        // 45..=49 is identity, 50..=54 is shifted by 2
+        assert_eq!(
+            map_ranges(vec![Range::new(45, 54)], &map),
+            [Range::new(45, 49), Range::new(52, 56)]
+        );
+        // 97 is shifted by 2, 98..=99 wrap to 50..=51, 100..=101 are identity
+        assert_eq!(
+            map_ranges(vec![Range::new(97, 101)], &map),
+            [Range::new(50, 51), Range::new(99, 101)]
+        );
+        assert_eq!(map_ranges(vec![Range::new(1, 5)], &map), [Range::new(1, 5)]);
+        assert_eq!(map_ranges(Vec::new(), &map), []);
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n";
+        let almanac = parse_almanac(input).unwrap();
+        assert_eq!(almanac.seeds, [79, 14]);
+        assert_eq!(almanac.seeds_line, 1);
+        assert_eq!(
+            almanac.maps,
+            [vec![(50, 98, 2), (52, 50, 48)], vec![(0, 15, 37)]]
+        );
+    }
+
+    #[test]
+    fn test_map_value() {
+        let map = vec![(50, 98, 2), (52, 50, 48)];
+        assert_eq!(map_value(79, &map), 81);
+        assert_eq!(map_value(14, &map), 14);
+        assert_eq!(map_value(98, &map), 50);
+        assert_eq!(map_value(99, &map), 51);
+        assert_eq!(map_value(100, &map), 100);
+    }
+
+    #[test]
+    fn test_empty_map_range() {
+        // a 0-length range maps nothing, even at its own source start
+        let map = vec![(0, 10, 0)];
+        assert_eq!(map_value(10, &map), 10);
+        assert_eq!(map_value(9, &map), 9);
+        assert_eq!(
+            map_ranges(vec![Range::new(5, 15)], &map),
+            [Range::new(5, 15)]
+        );
+    }
+}
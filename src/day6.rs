@@ -0,0 +1,408 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use crate::{day::Day, error::AocError, parse};
+
+/// --- Day 6: Wait For It ---
+///
+/// The ferry quickly brings you across Island Island. After asking around,
+/// you discover that there is indeed normally a large pile of sand somewhere near here,
+/// but you don't see anything besides lots of water and the small island where the ferry has docked.
+///
+/// As part of signing up, you get a sheet of paper (your puzzle input) that lists
+/// the time allowed for each race and also the best distance ever recorded in that race.
+/// To guarantee you win the grand prize, you need to make sure you go farther in each race
+/// than the current record holder.
+///
+/// Holding down the button charges the boat, and releasing the button allows the boat to move.
+/// Boats move faster if their button was held longer, but time spent holding the button counts
+/// against the total race time. You can only hold the button at the start of the race,
+/// and boats don't move until the button is released.
+///
+/// For example:
+///
+/// Time:      7  15   30
+/// Distance:  9  40  200
+///
+/// Your toy boat has a starting speed of zero millimeters per millisecond.
+/// For each whole millisecond you spend at the beginning of the race holding down the button,
+/// the boat's speed increases by one millimeter per millisecond.
+///
+/// In the first race, there are 4 ways to beat the record, 8 in the second race and 9 in the third.
+/// If you multiply these values together, you get 288 (4 * 8 * 9).
+///
+/// Determine the number of ways you could beat the record in each race.
+/// What do you get if you multiply these numbers together?
+///
+/// Returns the product of the numbers of ways to win on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day6_step1() -> Result<u64, AocError> {
+    let input = day::read_input(6)?;
+
+    day6_step1_str(&input)
+}
+
+/// Returns the product of the numbers of hold times beating the record of each race of `input`
+/// on success
+///
+/// Returns an `AocError::Parse` if a line is missing, there is no race
+/// or the races and records do not pair up
+///
+/// `input` : the `Time:` and `Distance:` lines
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day6::day6_step1_str;
+/// let input = "Time: 7 15\nDistance: 9 40";
+/// assert_eq!(day6_step1_str(input).unwrap(), 4 * 8);
+/// assert!(day6_step1_str("Time: 7 15\nDistance: 9").is_err());
+/// ```
+pub fn day6_step1_str(input: &str) -> Result<u64, AocError> {
+    let races = races(&parse_sheet(input)?)?;
+
+    Ok(ways_to_win_all(&races))
+}
+
+/// --- Part Two ---
+///
+/// As the race is about to start, you realize the piece of paper with race times
+/// and record distances you got earlier actually just has very bad kerning.
+/// There's really only one race - ignore the spaces between the numbers on each line.
+///
+/// So, the example from before:
+///
+/// Time:      7  15   30
+/// Distance:  9  40  200
+///
+/// ...now instead means this:
+///
+/// Time:      71530
+/// Distance:  940200
+///
+/// Now, you have to figure out how many ways there are to win this single race.
+/// In this example, the race lasts for 71530 milliseconds and the record distance you need to beat
+/// is 940200 millimeters. You could hold the button anywhere from 14 to 71516 milliseconds
+/// and beat the record, a total of 71503 ways!
+///
+/// How many ways can you beat the record in this one much longer race?
+///
+/// Returns the number of ways to win on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day6_step2() -> Result<u64, AocError> {
+    let input = day::read_input(6)?;
+
+    day6_step2_str(&input)
+}
+
+/// Returns the number of hold times beating the record of the single race of `input` on success
+///
+/// Returns an `AocError::Parse` if a line is missing or its digits do not make a `u64`
+///
+/// `input` : the `Time:` and `Distance:` lines, the spaces between digits being ignored
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day6::day6_step2_str;
+/// let input = "Time: 7 15 30\nDistance: 9 40 200";
+/// assert_eq!(day6_step2_str(input).unwrap(), 71503);
+/// assert!(day6_step2_str("Time: 7 15 30").is_err());
+/// ```
+pub fn day6_step2_str(input: &str) -> Result<u64, AocError> {
+    let (time, distance) = kerned_race(&parse_sheet(input)?)?;
+
+    Ok(ways_to_win(time, distance))
+}
+
+/// Returns the product of the numbers of ways to win the races of `input` and the number of ways
+/// to win its single kerned race on success, reading the sheet once for both
+///
+/// Returns an `AocError::Parse` if the sheet is malformed for either reading
+///
+/// `input` : the `Time:` and `Distance:` lines
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day6::day6_both;
+/// let input = "Time: 7 15\nDistance: 9 40";
+/// assert_eq!(day6_both(input).unwrap(), (4 * 8, 712));
+/// ```
+pub fn day6_both(input: &str) -> Result<(u64, u64), AocError> {
+    let sheet = parse_sheet(input)?;
+    let races = races(&sheet)?;
+    let (time, distance) = kerned_race(&sheet)?;
+
+    Ok((ways_to_win_all(&races), ways_to_win(time, distance)))
+}
+
+/// The solution of day 6, see `day6_step1_str` and `day6_step2_str`
+pub struct Day6;
+
+impl Day for Day6 {
+    fn number(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        day6_step1_str(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<u64, AocError> {
+        day6_step2_str(input)
+    }
+}
+
+/// The two lines of the sheet of paper, each with its line number (1-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sheet<'a> {
+    times: (usize, &'a str),
+    distances: (usize, &'a str),
+}
+
+/// Returns the `Time:` and `Distance:` lines of `input`, without their label, on success
+///
+/// Returns an `AocError::Parse` if one of them is missing
+fn parse_sheet(input: &str) -> Result<Sheet<'_>, AocError> {
+    let mut times = None;
+    let mut distances = None;
+
+    for (i, line) in input.lines().map(str::trim).enumerate() {
+        if let Some(rest) = line.strip_prefix("Time:") {
+            times = Some((i + 1, rest));
+        } else if let Some(rest) = line.strip_prefix("Distance:") {
+            distances = Some((i + 1, rest));
+        }
+    }
+
+    // a missing line is reported where the sheet would have it
+    Ok(Sheet {
+        times: times.ok_or_else(|| parse_error(1, "missing Time line"))?,
+        distances: distances.ok_or_else(|| parse_error(2, "missing Distance line"))?,
+    })
+}
+
+/// Returns the races of the sheet, as `(time, record distance)`, on success
+///
+/// Returns an `AocError::Parse` if there is no race or a race has no record, or the other way round
+fn races(sheet: &Sheet) -> Result<Vec<(u64, u64)>, AocError> {
+    let times = parse::uints(sheet.times.1);
+    let distances = parse::uints(sheet.distances.1);
+    if times.is_empty() {
+        return Err(parse_error(sheet.times.0, "no races"));
+    }
+    if times.len() != distances.len() {
+        return Err(parse_error(
+            sheet.distances.0,
+            &format!("{} records for {} races", distances.len(), times.len()),
+        ));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// Returns the parse error of the sheet at `line`
+fn parse_error(line: usize, msg: &str) -> AocError {
+    AocError::Parse {
+        day: 6,
+        line,
+        msg: msg.to_string(),
+    }
+}
+
+/// Returns the time and record distance of the sheet read as a single race on success
+///
+/// Returns an `AocError::Parse` if a line has no digit or too many for a `u64`
+///
+/// The digits of each line are concatenated, eg `7  15   30` is `71530`
+fn kerned_race(sheet: &Sheet) -> Result<(u64, u64), AocError> {
+    let number = |(line, rest): (usize, &str)| {
+        let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+        digits
+            .parse()
+            .map_err(|_| parse_error(line, &format!("'{digits}' is not a race number")))
+    };
+
+    Ok((number(sheet.times)?, number(sheet.distances)?))
+}
+
+/// Returns the product of the numbers of ways to win each of the `(time, record distance)` races
+fn ways_to_win_all(races: &[(u64, u64)]) -> u64 {
+    races
+        .iter()
+        .map(|(time, distance)| ways_to_win(*time, *distance))
+        .product()
+}
+
+/// Returns how many whole hold times `t` go farther than `distance`, ie `t * (time - t) > distance`
+///
+/// The winning hold times lie strictly between the roots of `t² - time * t + distance`,
+/// symmetric around `time / 2`: the lower root is estimated with floats then nudged to
+/// the first winning hold time, so large races don't need a loop
+///
+/// `time` : the duration of the race
+///
+/// `distance` : the record to beat
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let beats = |t: u64| u128::from(t) * u128::from(time - t) > u128::from(distance);
+
+    let discriminant = (time as f64).powi(2) - 4.0 * distance as f64;
+    if discriminant < 0.0 {
+        return 0;
+    }
+
+    // float rounding may put the estimate a little off the first winning hold time
+    let mut low = (((time as f64 - discriminant.sqrt()) / 2.0).floor() as u64).min(time / 2);
+    while low <= time / 2 && !beats(low) {
+        low += 1;
+    }
+    if low > time / 2 {
+        return 0;
+    }
+    while low > 0 && beats(low - 1) {
+        low -= 1;
+    }
+
+    time - 2 * low + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day6_step1_str() {
+        assert_eq!(day6_step1_str("Time: 7 15\nDistance: 9 40").unwrap(), 4 * 8);
+
+        for (input, line) in [
+            ("", 1),
+            ("Distance: 9", 1),
+            ("Time: 7", 2),
+            ("Time:\nDistance:", 1),
+            // every race needs its record
+            ("Time: 7 15\nDistance: 9", 2),
+            ("Time: 7\nDistance: 9 40", 2),
+        ] {
+            assert!(
+                matches!(
+                    day6_step1_str(input),
+                    Err(AocError::Parse { day: 6, line: l, .. }) if l == line
+                ),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_day6_step2_str() {
+        assert_eq!(day6_step2_str("Time: 7 15\nDistance: 9 40").unwrap(), 712);
+        assert!(matches!(
+            day6_step2_str(""),
+            Err(AocError::Parse {
+                day: 6,
+                line: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            day6_step2_str("Time: 7\nDistance:"),
+            Err(AocError::Parse {
+                day: 6,
+                line: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_day6_both() {
+        let input = "Time: 7 15\nDistance: 9 40";
+        assert_eq!(
+            day6_both(input).unwrap(),
+            (
+                day6_step1_str(input).unwrap(),
+                day6_step2_str(input).unwrap()
+            )
+        );
+        assert!(day6_both("Time: 7 15\nDistance: 9").is_err());
+    }
+
+    #[test]
+    fn test_kerned_race() {
+        let sheet = parse_sheet("Time: 7 15\nDistance:  9  40").unwrap();
+        assert_eq!(kerned_race(&sheet).unwrap(), (715, 940));
+
+        // 20 digits do not fit in a u64
+        let sheet = parse_sheet("Time: 9999999999 9999999999\nDistance: 1").unwrap();
+        assert!(matches!(
+            kerned_race(&sheet),
+            Err(AocError::Parse {
+                day: 6,
+                line: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_sheet() {
+        assert_eq!(
+            parse_sheet("\nTime: 7 15\nDistance:  9  40").unwrap(),
+            Sheet {
+                times: (2, " 7 15"),
+                distances: (3, "  9  40")
+            }
+        );
+        assert!(parse_sheet("").is_err());
+    }
+
+    #[test]
+    fn test_races() {
+        let sheet = parse_sheet("Time: 7 15\nDistance:  9  40").unwrap();
+        assert_eq!(races(&sheet).unwrap(), [(7, 9), (15, 40)]);
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(ways_to_win(7, 9), 4);
+        assert_eq!(ways_to_win(15, 40), 8);
+        assert_eq!(ways_to_win(30, 200), 9);
+        assert_eq!(ways_to_win(4, 4), 0);
+        assert_eq!(ways_to_win(0, 0), 0);
+        assert_eq!(ways_to_win(71530, 940200), 71503);
+    }
+
+    #[test]
+    fn test_ways_to_win_large_race() {
+        // an 8-digit race, as found in real inputs once kerned
+        assert_eq!(ways_to_win(40829166, 277133813491063), 23632299);
+        // t * (time - t) no longer fits in a u64
+        assert_eq!(ways_to_win(10_000_000_000, 0), 9_999_999_999);
+
+        let (time, distance) = (100_000u64, 1_234_567_890u64);
+        let expected = (0..=time)
+            .filter(|t| u128::from(*t) * u128::from(time - t) > u128::from(distance))
+            .count() as u64;
+        assert_eq!(ways_to_win(time, distance), expected);
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_loop() {
+        for time in 0..60 {
+            for distance in 0..time * time / 4 + 2 {
+                let expected = (0..=time).filter(|t| t * (time - t) > distance).count() as u64;
+                assert_eq!(ways_to_win(time, distance), expected, "{time}/{distance}");
+            }
+        }
+    }
+}
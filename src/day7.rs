@@ -0,0 +1,242 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::day;
+use std::io;
+
+use crate::{day::Day, error::AocError};
+
+/// The cards from weakest to strongest
+const CARDS: &[u8; 13] = b"23456789TJQKA";
+
+/// The type of a hand, ordered from weakest to strongest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// --- Day 7: Camel Cards ---
+///
+/// Your all-expenses-paid trip turns out to be a one-way, five-minute ride in an airship.
+///
+/// In Camel Cards, you get a list of hands, and your goal is to order them based on the strength
+/// of each hand. A hand consists of five cards labeled one of A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3, or 2.
+/// The relative strength of each card follows this order, where A is the highest and 2 is the lowest.
+///
+/// Every hand is exactly one type. From strongest to weakest, they are:
+/// five of a kind, four of a kind, full house, three of a kind, two pair, one pair and high card.
+///
+/// Hands are primarily ordered based on type. If two hands have the same type,
+/// a second ordering rule takes effect: compare the first card in each hand.
+/// If these cards are different, the hand with the stronger first card is considered stronger.
+/// If the first card in each hand have the same label, however, then move on to
+/// the second card in each hand, and so on.
+///
+/// To play Camel Cards, you are given a list of hands and their corresponding bid (your puzzle input).
+/// For example:
+///
+/// 32T3K 765
+/// T55J5 684
+/// KK677 28
+/// KTJJT 220
+/// QQQJA 483
+///
+/// Each hand wins an amount equal to its bid multiplied by its rank, where the weakest hand
+/// gets rank 1, the second-weakest hand gets rank 2, and so on up to the strongest hand.
+/// In this example, the total winnings are 765 * 1 + 220 * 2 + 28 * 3 + 684 * 4 + 483 * 5 = 6440.
+///
+/// Find the rank of every hand in your set. What are the total winnings?
+///
+/// Returns the total winnings on success
+///
+/// Returns an `AocError` on failure
+#[cfg(not(target_arch = "wasm32"))]
+pub fn day7_step1() -> Result<u64, AocError> {
+    let input = day::read_input(7)?;
+
+    day7_step1_str(&input)
+}
+
+/// Returns the total winnings of the hands of `input`, each bid multiplied by the rank of its hand,
+/// on success
+///
+/// Returns an `AocError::Parse` on the first line that is not five valid cards and a bid,
+/// as skipping it would change the rank of the other hands
+///
+/// `input` : the hands and their bids, one per line
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::day7::day7_step1_str;
+/// let input = "23456 10\nAA234 1";
+/// assert_eq!(day7_step1_str(input).unwrap(), 10 * 1 + 1 * 2);
+/// assert!(day7_step1_str("2345X 10").is_err());
+/// ```
+pub fn day7_step1_str(input: &str) -> Result<u64, AocError> {
+    let mut hands = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (cards, bid) = parse_hand(line).map_err(|msg| AocError::Parse {
+            day: 7,
+            line: i + 1,
+            msg: msg.to_string(),
+        })?;
+        hands.push((hand_type(&cards), cards, bid));
+    }
+
+    hands.sort_unstable();
+
+    Ok(hands
+        .iter()
+        .zip(1..)
+        .map(|((_, _, bid), rank)| rank * bid)
+        .sum())
+}
+
+/// The solution of day 7, see `day7_step1_str`
+///
+/// The second part is not solved yet, it returns an `AocError::Io` of kind `Unsupported`
+pub struct Day7;
+
+impl Day for Day7 {
+    fn number(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Result<u64, AocError> {
+        day7_step1_str(input)
+    }
+
+    fn part2(&self, _input: &str) -> Result<u64, AocError> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "the second part of day 7 is not solved yet",
+        )
+        .into())
+    }
+}
+
+/// Returns the card strengths and the bid of the `hand bid` line on success
+///
+/// Returns what is wrong with the line on failure
+fn parse_hand(line: &str) -> Result<([u8; 5], u64), &'static str> {
+    let (hand, bid) = line.trim().split_once(' ').ok_or("missing bid")?;
+    let cards = card_strengths(hand).ok_or("hand is not five cards of 23456789TJQKA")?;
+    let bid = bid.trim().parse().map_err(|_| "invalid bid")?;
+
+    Ok((cards, bid))
+}
+
+/// Returns the strengths of the five cards of `hand`, `None` if it is not five valid cards
+fn card_strengths(hand: &str) -> Option<[u8; 5]> {
+    let mut strengths = [0; 5];
+    let bytes = hand.as_bytes();
+    if bytes.len() != strengths.len() {
+        return None;
+    }
+
+    for (strength, card) in strengths.iter_mut().zip(bytes) {
+        *strength = CARDS.iter().position(|c| c == card)? as u8;
+    }
+
+    Some(strengths)
+}
+
+/// Returns the type of the hand of card strengths `cards`, from how many times each card occurs
+///
+/// `cards` : the strengths of the five cards, as indices in `CARDS` built by `card_strengths`
+fn hand_type(cards: &[u8; 5]) -> HandType {
+    let mut counts = [0u8; CARDS.len()];
+    for card in cards {
+        counts[*card as usize] += 1;
+    }
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match (counts[0], counts[1]) {
+        (5, _) => HandType::FiveOfAKind,
+        (4, _) => HandType::FourOfAKind,
+        (3, 2) => HandType::FullHouse,
+        (3, _) => HandType::ThreeOfAKind,
+        (2, 2) => HandType::TwoPair,
+        (2, _) => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day7_step1_str() {
+        assert_eq!(day7_step1_str("").unwrap(), 0);
+        assert_eq!(day7_step1_str("\n23456 10\n\nAA234 1\n").unwrap(), 12);
+    }
+
+    #[test]
+    fn test_day7_step1_malformed() {
+        for (input, line) in [
+            ("23456 10\nQQQXA 1", 2),
+            ("2345 10", 1),
+            ("23456", 1),
+            ("23456 ten", 1),
+            ("\n23456 -1", 2),
+        ] {
+            assert!(
+                matches!(
+                    day7_step1_str(input),
+                    Err(AocError::Parse { day: 7, line: l, .. }) if l == line
+                ),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_hand() {
+        assert_eq!(parse_hand("32T3K 765"), Ok(([1, 0, 8, 1, 11], 765)));
+        assert_eq!(parse_hand("32T3K"), Err("missing bid"));
+        assert_eq!(parse_hand("32T3K x"), Err("invalid bid"));
+        assert!(parse_hand("32T3Z 1").is_err());
+    }
+
+    #[test]
+    fn test_card_strengths() {
+        assert_eq!(card_strengths("23TKA"), Some([0, 1, 8, 11, 12]));
+        assert_eq!(card_strengths("2345"), None);
+        assert_eq!(card_strengths("2345X"), None);
+    }
+
+    #[test]
+    fn test_hand_type() {
+        let hand = |s| hand_type(&card_strengths(s).unwrap());
+        assert_eq!(hand("AAAAA"), HandType::FiveOfAKind);
+        assert_eq!(hand("AA8AA"), HandType::FourOfAKind);
+        assert_eq!(hand("23332"), HandType::FullHouse);
+        assert_eq!(hand("TTT98"), HandType::ThreeOfAKind);
+        assert_eq!(hand("23432"), HandType::TwoPair);
+        assert_eq!(hand("A23A4"), HandType::OnePair);
+        assert_eq!(hand("23456"), HandType::HighCard);
+        assert!(HandType::HighCard < HandType::OnePair);
+        assert!(HandType::FullHouse < HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn test_tiebreak() {
+        // same type, the first differing card decides
+        let input = "2AAAA 1\n33332 2";
+        assert_eq!(day7_step1_str(input).unwrap(), 1 + 2 * 2);
+        let input = "KTJJT 1\nKK677 2";
+        assert_eq!(day7_step1_str(input).unwrap(), 1 + 2 * 2);
+    }
+}
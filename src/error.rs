@@ -0,0 +1,70 @@
+use std::{error::Error, fmt, io};
+
+/// The error type shared by every day of the calendar
+#[derive(Debug)]
+pub enum AocError {
+    /// Reading the puzzle input failed
+    Io(io::Error),
+    /// The puzzle input of `day` could not be found
+    MissingInput { day: u8 },
+    /// The puzzle input of `day` is malformed at `line` (1-based)
+    Parse { day: u8, line: usize, msg: String },
+    /// Downloading the puzzle input of `day` failed
+    Fetch { day: u8, msg: String },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "io error: {e}"),
+            AocError::MissingInput { day } => write!(f, "missing input for day {day}"),
+            AocError::Parse { day, line, msg } => {
+                write!(f, "parse error in day {day} input, line {line}: {msg}")
+            }
+            AocError::Fetch { day, msg } => write!(f, "cannot fetch input of day {day}: {msg}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = AocError::MissingInput { day: 3 };
+        assert_eq!(e.to_string(), "missing input for day 3");
+
+        let e = AocError::Parse {
+            day: 2,
+            line: 7,
+            msg: "no game id".to_string(),
+        };
+        assert_eq!(
+            e.to_string(),
+            "parse error in day 2 input, line 7: no game id"
+        );
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        let e = AocError::from(io::Error::other("boom"));
+        assert!(matches!(e, AocError::Io(_)));
+        assert!(e.source().is_some());
+    }
+}
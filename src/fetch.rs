@@ -0,0 +1,40 @@
+use std::fs;
+
+use crate::{day, error::AocError};
+
+const USER_AGENT: &str = "github.com/pmenuettang/Advent-of-Code-2023";
+
+/// Returns the puzzle input of `day` on success, downloading it from adventofcode.com if needed
+///
+/// Returns an `AocError` on failure
+///
+/// The input is cached to `day::input_path(day)`: once the file exists, it is read
+/// instead of being downloaded again, as the site asks.
+///
+/// `day` : the day of the input
+///
+/// `session` : the value of the `session` cookie of a logged-in adventofcode.com account
+pub fn fetch_input(day: u8, session: &str) -> Result<String, AocError> {
+    let path = day::input_path(day);
+    if path.exists() {
+        return Ok(fs::read_to_string(&path)?);
+    }
+
+    let url = format!("https://adventofcode.com/2023/day/{day}/input");
+    let input = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| AocError::Fetch {
+            day,
+            msg: e.to_string(),
+        })?
+        .into_string()?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, &input)?;
+
+    Ok(input)
+}
@@ -0,0 +1,146 @@
+use std::ops::{Add, Sub};
+
+/// A position on a 2D plane, `y` growing southward like the rows of a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    /// Returns the point at `(x, y)`
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns the Manhattan distance between `self` and `other`
+    ///
+    /// `other` : the point to measure the distance to
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::geometry::Point;
+    /// let distance = Point::new(1, 6).manhattan(&Point::new(5, 11));
+    /// assert_eq!(distance, 9);
+    /// ```
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// A cardinal direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// The four directions, clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns the move of one step in this direction
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::geometry::{Direction, Point};
+    /// let p = Point::new(2, 2) + Direction::North.delta();
+    /// assert_eq!(p, Point::new(2, 1));
+    /// ```
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Returns the direction after a quarter turn counterclockwise
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Returns the direction after a quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Returns the direction after a half turn
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_ops() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(b.manhattan(&a), 11);
+        assert_eq!(a.manhattan(&a), 0);
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::default());
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+}
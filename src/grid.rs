@@ -0,0 +1,215 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A rectangular 2D map, indexed by `(row, col)` from the top-left corner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T = u8> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// The error returned when the rows of a grid do not all have the same width
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridError {
+    /// The offending row (0-based)
+    pub row: usize,
+    /// The width of the first row
+    pub expected: usize,
+    /// The width of the offending row
+    pub found: usize,
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has width {}, expected {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl Error for GridError {}
+
+const DELTAS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const DELTAS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+impl FromStr for Grid<u8> {
+    type Err = GridError;
+
+    /// Parses one row per non-empty line, one cell per byte
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::grid::Grid;
+    /// let grid: Grid = "ab\ncd\n".parse().unwrap();
+    /// assert_eq!(grid.get(1, 0), Some(b'c'));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::with_capacity(s.len());
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in s.lines().filter(|line| !line.is_empty()) {
+            let line = line.as_bytes();
+            if height == 0 {
+                width = line.len();
+            } else if line.len() != width {
+                return Err(GridError {
+                    row: height,
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+
+            cells.extend_from_slice(line);
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Returns the number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at `(row, col)`, `None` if it is out of the grid
+    ///
+    /// `row` : the row of the cell
+    ///
+    /// `col` : the column of the cell
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row < self.height && col < self.width {
+            Some(self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the cells as `(row, col, cell)`, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (i / self.width, i % self.width, *cell))
+    }
+
+    /// Returns the in-grid coordinates of the 4 orthogonal neighbors of `(row, col)`
+    ///
+    /// `row` : the row of the cell
+    ///
+    /// `col` : the column of the cell
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::grid::Grid;
+    /// let grid: Grid = "abc\ndef".parse().unwrap();
+    /// let neighbors: Vec<_> = grid.neighbors4(0, 0).collect();
+    /// assert_eq!(neighbors, [(0, 1), (1, 0)]);
+    /// ```
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &DELTAS4)
+    }
+
+    /// Returns the in-grid coordinates of the 8 neighbors of `(row, col)`, diagonals included
+    ///
+    /// `row` : the row of the cell
+    ///
+    /// `col` : the column of the cell
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &DELTAS8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        deltas: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        deltas.iter().filter_map(move |(dr, dc)| {
+            let r = row.checked_add_signed(*dr)?;
+            let c = col.checked_add_signed(*dc)?;
+            (r < self.height && c < self.width).then_some((r, c))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let grid: Grid = "467..\n...*.\n".parse().unwrap();
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some(b'4'));
+        assert_eq!(grid.get(1, 3), Some(b'*'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 5), None);
+
+        let grid = "abc\nde\n".parse::<Grid>();
+        assert_eq!(
+            grid,
+            Err(GridError {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let grid: Grid = "".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid: Grid = "ab\ncd".parse().unwrap();
+        let cells: Vec<_> = grid.iter().collect();
+        assert_eq!(
+            cells,
+            [(0, 0, b'a'), (0, 1, b'b'), (1, 0, b'c'), (1, 1, b'd')]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid = "abc\ndef\nghi".parse().unwrap();
+
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+
+        let corner: Vec<_> = grid.neighbors8(2, 2).collect();
+        assert_eq!(corner, [(1, 1), (1, 2), (2, 1)]);
+
+        let edge: Vec<_> = grid.neighbors4(0, 1).collect();
+        assert_eq!(edge, [(0, 2), (1, 1), (0, 0)]);
+    }
+}
@@ -1 +1,18 @@
-pub mod day1;
\ No newline at end of file
+pub mod day;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod pathfinding;
+pub mod ranges;
+pub mod timing;
@@ -1,17 +1,298 @@
-use std::error::Error;
+use std::{
+    env,
+    error::Error,
+    io::{self, Read},
+    ops::RangeInclusive,
+    process,
+};
 
-use advent_of_code_2023::day1;
+use advent_of_code_2023::{
+    day::{self, Day, DayResult},
+    error::AocError,
+    timing,
+};
+
+const USAGE: &str =
+    "usage: advent-of-code-2023 [--day <1-25> | --all] [--part <1-2>] [--stdin | --fetch] [--json]";
 
 fn main() {
-    match run()     {
-        Ok(_) => println!("Done."),
-        Err(e) => println!("{e}"),
+    if let Err(e) = run() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Options of the command line
+#[derive(Debug, PartialEq)]
+struct Args {
+    /// The day to run, day 1 by default
+    day: u8,
+    /// The part to run, both parts when `None`
+    part: Option<u8>,
+    /// Whether the puzzle input is read from stdin instead of the input folder
+    stdin: bool,
+    /// Whether a missing puzzle input is downloaded, with the session token of `AOC_SESSION`
+    fetch: bool,
+    /// Whether the results are printed as JSON instead of text
+    json: bool,
+    /// Whether every implemented day is run, instead of `day` only
+    all: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        day: 1,
+        part: None,
+        stdin: false,
+        fetch: false,
+        json: false,
+        all: false,
+    };
+
+    let mut day_given = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => {
+                parsed.day = parse_value(&arg, args.next(), 1..=25)?;
+                day_given = true;
+            }
+            "--part" => parsed.part = Some(parse_value(&arg, args.next(), 1..=2)?),
+            "--stdin" => parsed.stdin = true,
+            "--fetch" => parsed.fetch = true,
+            "--json" => parsed.json = true,
+            "--all" => parsed.all = true,
+            _ => return Err(format!("unknown argument '{arg}'\n{USAGE}")),
+        }
+    }
+
+    // --all reads every input from the input folder
+    let conflict = if parsed.stdin && parsed.fetch {
+        Some(("--stdin", "--fetch"))
+    } else if parsed.all && day_given {
+        Some(("--all", "--day"))
+    } else if parsed.all && parsed.stdin {
+        Some(("--all", "--stdin"))
+    } else if parsed.all && parsed.fetch {
+        Some(("--all", "--fetch"))
+    } else {
+        None
+    };
+    if let Some((a, b)) = conflict {
+        return Err(format!("{a} cannot be used with {b}\n{USAGE}"));
+    }
+
+    Ok(parsed)
+}
+
+fn parse_value(flag: &str, value: Option<String>, range: RangeInclusive<u8>) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}\n{USAGE}"))?;
+    match value.parse::<u8>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!(
+            "invalid value '{value}' for {flag}, expected {}-{}\n{USAGE}",
+            range.start(),
+            range.end()
+        )),
     }
 }
 
 fn run() -> Result<(), Box<dyn Error>> {
-    println!("Day 1 part 1 : total from input is {}.", day1::day1_step1()?);
-    println!("Day 1 part 2 : total from input is {}.", day1::day1_step2()?);
+    let args = parse_args(env::args().skip(1))?;
+    if args.all {
+        return run_all(&args);
+    }
+
+    let day = day::all_days()
+        .into_iter()
+        .find(|day| day.number() == args.day)
+        .ok_or_else(|| format!("day {} is not implemented yet", args.day))?;
+
+    let input = if args.stdin {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else if args.fetch {
+        fetch_input(args.day)?
+    } else {
+        read_input(args.day)?
+    };
+
+    let results = solve(day.as_ref(), &input, args.part)?;
+
+    if args.json {
+        print_json(&results);
+    } else {
+        for result in results {
+            println!(
+                "Day {} part {} : total from input is {} ({}µs).",
+                result.day, result.part, result.answer, result.micros
+            );
+        }
+        println!("Done.");
+    }
 
     Ok(())
 }
+
+/// Runs every implemented day whose input is available and prints a summary table
+fn run_all(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut results = Vec::new();
+    for day in day::all_days() {
+        let input = match read_input(day.number()) {
+            Ok(input) => input,
+            Err(AocError::MissingInput { day }) => {
+                eprintln!("warning: skipping day {day}, its input is missing");
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        results.extend(solve(day.as_ref(), &input, args.part)?);
+    }
+
+    if args.json {
+        print_json(&results);
+    } else {
+        print_table(&results);
+    }
+
+    Ok(())
+}
+
+/// Returns the timed answers of the requested parts of `day`, both parts when `part` is `None`
+fn solve(day: &dyn Day, input: &str, part: Option<u8>) -> Result<Vec<DayResult>, AocError> {
+    let mut results = Vec::new();
+    for p in [1, 2] {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+
+        let (answer, elapsed) = timing::timed(|| match p {
+            1 => day.part1(input),
+            _ => day.part2(input),
+        });
+        results.push(DayResult {
+            day: day.number(),
+            part: p,
+            answer: answer?,
+            micros: elapsed.as_micros(),
+        });
+    }
+
+    Ok(results)
+}
+
+#[cfg(feature = "serde")]
+fn print_json(results: &[DayResult]) {
+    let json = serde_json::to_string(results).expect("a DayResult always serializes");
+    println!("{json}");
+}
+
+#[cfg(not(feature = "serde"))]
+fn print_json(results: &[DayResult]) {
+    let objects: Vec<String> = results.iter().map(DayResult::to_json).collect();
+    println!("[{}]", objects.join(","));
+}
+
+fn print_table(results: &[DayResult]) {
+    println!(
+        "{:>3} | {:>4} | {:>20} | {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    println!("{:-<3}-+-{:-<4}-+-{:-<20}-+-{:-<12}", "", "", "", "");
+    for result in results {
+        println!(
+            "{:>3} | {:>4} | {:>20} | {:>10}µs",
+            result.day, result.part, result.answer, result.micros
+        );
+    }
+    println!("{:-<3}-+-{:-<4}-+-{:-<20}-+-{:-<12}", "", "", "", "");
+
+    let total: u128 = results.iter().map(|result| result.micros).sum();
+    println!("{:>3} | {:>4} | {:>20} | {:>10}µs", "", "", "Total", total);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_input(day: u8) -> Result<String, AocError> {
+    day::read_input(day)
+}
+
+/// There is no input folder on wasm, the input can only come from stdin
+#[cfg(target_arch = "wasm32")]
+fn read_input(_day: u8) -> Result<String, AocError> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the input folder is not available on wasm, use --stdin",
+    )
+    .into())
+}
+
+#[cfg(feature = "fetch")]
+fn fetch_input(day: u8) -> Result<String, Box<dyn Error>> {
+    let session = env::var("AOC_SESSION")
+        .map_err(|_| "--fetch needs the AOC_SESSION environment variable")?;
+
+    Ok(advent_of_code_2023::fetch::fetch_input(day, &session)?)
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch_input(_day: u8) -> Result<String, Box<dyn Error>> {
+    Err("--fetch needs the crate to be built with the `fetch` feature".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = args("").unwrap();
+        assert_eq!(
+            parsed,
+            Args {
+                day: 1,
+                part: None,
+                stdin: false,
+                fetch: false,
+                json: false,
+                all: false
+            }
+        );
+
+        let parsed = args("--day 7 --part 2 --stdin").unwrap();
+        assert_eq!(
+            parsed,
+            Args {
+                day: 7,
+                part: Some(2),
+                stdin: true,
+                fetch: false,
+                json: false,
+                all: false
+            }
+        );
+
+        assert!(args("--fetch").unwrap().fetch);
+        assert!(args("--json").unwrap().json);
+        assert!(args("--all").unwrap().all);
+        assert!(args("--all --part 2 --json").is_ok());
+
+        assert!(args("--day 40").is_err());
+        assert!(args("--day").is_err());
+        assert!(args("--part 3").is_err());
+        assert!(args("--verbose").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_conflicts() {
+        assert!(args("--stdin --fetch").is_err());
+        assert!(args("--all --day 3").is_err());
+        assert!(args("--day 3 --all").is_err());
+        assert!(args("--all --stdin").is_err());
+        assert!(args("--all --fetch").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+/// Returns the greatest common divisor of `a` and `b`, with `gcd(0, n) == n`
+///
+/// `a` : the first number
+///
+/// `b` : the second number
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::math::gcd;
+/// assert_eq!(gcd(12, 18), 6);
+/// ```
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`, 0 if either is 0
+///
+/// `a` : the first number
+///
+/// `b` : the second number
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::math::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    // divide first so that the intermediate value never exceeds the result
+    a / gcd(a, b) * b
+}
+
+/// Returns the least common multiple of all of `values`, 1 if there is none
+///
+/// `values` : the numbers
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::math::lcm_all;
+/// assert_eq!(lcm_all(&[3, 4, 5]), 60);
+/// ```
+pub fn lcm_all(values: &[u64]) -> u64 {
+    values.iter().fold(1, |acc, value| lcm(acc, *value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(48, 180), 12);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(7, 7), 7);
+
+        let big = u64::MAX / 2;
+        assert_eq!(lcm(big, big), big);
+        assert_eq!(lcm(big, 1), big);
+    }
+
+    #[test]
+    fn test_lcm_all() {
+        assert_eq!(lcm_all(&[3, 4, 5]), 60);
+        assert_eq!(lcm_all(&[2, 3, 4, 6]), 12);
+        assert_eq!(lcm_all(&[]), 1);
+    }
+}
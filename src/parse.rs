@@ -0,0 +1,89 @@
+/// Returns every integer of `line`, in order, a `-` right before digits making it negative
+///
+/// Letters and punctuation around the numbers are ignored,
+/// as well as numbers too large for an `i64`
+///
+/// `line` : the text to scan
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::parse;
+/// assert_eq!(parse::ints("Game 3: 4 red, -2 blue"), [3, 4, -2]);
+/// ```
+pub fn ints(line: &str) -> Vec<i64> {
+    digit_runs(line, true)
+        .filter_map(|run| run.parse().ok())
+        .collect()
+}
+
+/// Returns every unsigned integer of `line`, in order
+///
+/// Letters and punctuation around the numbers are ignored, a `-` included,
+/// as well as numbers too large for a `u64`
+///
+/// `line` : the text to scan
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::parse;
+/// assert_eq!(parse::uints("Card 1: 41 48 | 83 -86"), [1, 41, 48, 83, 86]);
+/// ```
+pub fn uints(line: &str) -> Vec<u64> {
+    digit_runs(line, false)
+        .filter_map(|run| run.parse().ok())
+        .collect()
+}
+
+/// Returns the runs of ASCII digits of `line`, with their leading `-` when `signed`
+fn digit_runs(line: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+
+        let mut start = i;
+        if signed && start > 0 && bytes[start - 1] == b'-' {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        Some(&line[start..i])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints("Game 3: 4 red, -2 blue"), [3, 4, -2]);
+        assert_eq!(ints("px, 19 @ -2,1,-12"), [19, -2, 1, -12]);
+        assert_eq!(ints("0 3 6 9 12 15"), [0, 3, 6, 9, 12, 15]);
+        assert!(ints("no numbers - here").is_empty());
+        assert!(ints("").is_empty());
+        assert_eq!(ints("--5"), [-5]);
+    }
+
+    #[test]
+    fn test_uints() {
+        assert_eq!(uints("Time:      7  15   30"), [7, 15, 30]);
+        assert_eq!(uints("seeds: 79 14 55 13"), [79, 14, 55, 13]);
+        assert_eq!(uints("x-2"), [2]);
+        assert_eq!(uints("99999999999999999999 1"), [1]);
+    }
+}
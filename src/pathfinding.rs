@@ -0,0 +1,113 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashSet},
+    hash::Hash,
+};
+
+/// A state waiting in the queue, ordered by its cost only
+struct Queued<N> {
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+/// Returns the minimum total cost from `start` to any goal state on success
+///
+/// Returns `None` if no goal state is reachable
+///
+/// `start` : the initial state
+///
+/// `successors` : returns the states reachable in one step from a state, with the cost of the step
+///
+/// `is_goal` : returns whether a state is a goal
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::pathfinding::dijkstra;
+/// // count up from 1 to 10, each step costing the number reached
+/// let cost = dijkstra(1u64, |n| [(n + 1, n + 1)], |n| *n == 10);
+/// assert_eq!(cost, Some((2..=10).sum()));
+/// ```
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl Fn(&N) -> I,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<u64>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse(Queued {
+        cost: 0,
+        node: start,
+    }));
+
+    while let Some(Reverse(Queued { cost, node })) = queue.pop() {
+        if is_goal(&node) {
+            return Some(cost);
+        }
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            if !visited.contains(&next) {
+                queue.push(Reverse(Queued {
+                    cost: cost + step,
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dijkstra() {
+        // a -1-> b -1-> c -1-> d and a -5-> d, the long way round is cheaper
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        assert_eq!(dijkstra('a', edges, |n| *n == 'd'), Some(3));
+        assert_eq!(dijkstra('a', edges, |n| *n == 'a'), Some(0));
+        assert_eq!(dijkstra('a', edges, |n| *n == 'z'), None);
+    }
+
+    #[test]
+    fn test_dijkstra_cycle() {
+        let edges = |n: &u8| [((n + 1) % 4, 2), ((n + 3) % 4, 7)];
+        assert_eq!(dijkstra(0, edges, |n| *n == 3), Some(6));
+    }
+}
@@ -0,0 +1,169 @@
+/// An inclusive range of integers, `start..=end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Range {
+    /// Returns the range `start..=end`
+    pub const fn new(start: i64, end: i64) -> Self {
+        Range { start, end }
+    }
+
+    /// Returns the number of integers in the range
+    pub fn size(&self) -> u64 {
+        self.end.abs_diff(self.start) + 1
+    }
+
+    /// Returns whether `value` is in the range
+    ///
+    /// `value` : the number to check
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Returns the integers in both `self` and `other`, `None` if there is none
+    ///
+    /// `other` : the range to intersect with
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::ranges::Range;
+    /// let common = Range::new(1, 5).intersect(&Range::new(4, 9));
+    /// assert_eq!(common, Some(Range::new(4, 5)));
+    /// ```
+    pub fn intersect(&self, other: &Range) -> Option<Range> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        (start <= end).then_some(Range::new(start, end))
+    }
+
+    /// Returns the integers of `self` not in `other`, as 0, 1 or 2 ranges in ascending order
+    ///
+    /// `other` : the range to remove
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use advent_of_code_2023::ranges::Range;
+    /// let rest = Range::new(1, 10).subtract(&Range::new(4, 6));
+    /// assert_eq!(rest, [Range::new(1, 3), Range::new(7, 10)]);
+    /// ```
+    pub fn subtract(&self, other: &Range) -> Vec<Range> {
+        let Some(common) = self.intersect(other) else {
+            return vec![*self];
+        };
+
+        let mut rest = Vec::with_capacity(2);
+        if self.start < common.start {
+            rest.push(Range::new(self.start, common.start - 1));
+        }
+        if common.end < self.end {
+            rest.push(Range::new(common.end + 1, self.end));
+        }
+
+        rest
+    }
+}
+
+/// Sorts `ranges` and coalesces the overlapping or adjacent ones
+///
+/// `ranges` : the ranges to merge in place
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::ranges::{merge, Range};
+/// let mut ranges = vec![Range::new(6, 10), Range::new(1, 5), Range::new(20, 25)];
+/// merge(&mut ranges);
+/// assert_eq!(ranges, [Range::new(1, 10), Range::new(20, 25)]);
+/// ```
+pub fn merge(ranges: &mut Vec<Range>) {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+    for range in ranges.drain(..) {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    *ranges = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        let a = Range::new(1, 10);
+        assert_eq!(a.intersect(&Range::new(3, 5)), Some(Range::new(3, 5)));
+        assert_eq!(a.intersect(&Range::new(-5, 20)), Some(a));
+        assert_eq!(a.intersect(&Range::new(-5, 1)), Some(Range::new(1, 1)));
+        assert_eq!(a.intersect(&Range::new(8, 20)), Some(Range::new(8, 10)));
+        assert_eq!(a.intersect(&Range::new(11, 20)), None);
+    }
+
+    #[test]
+    fn test_subtract() {
+        let a = Range::new(1, 10);
+        // fully contained
+        assert_eq!(
+            a.subtract(&Range::new(3, 5)),
+            [Range::new(1, 2), Range::new(6, 10)]
+        );
+        // fully covering
+        assert_eq!(a.subtract(&Range::new(0, 11)), []);
+        // partial overlap on each side
+        assert_eq!(a.subtract(&Range::new(-3, 4)), [Range::new(5, 10)]);
+        assert_eq!(a.subtract(&Range::new(8, 12)), [Range::new(1, 7)]);
+        // disjoint
+        assert_eq!(a.subtract(&Range::new(11, 12)), [a]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut ranges = vec![Range::new(1, 5), Range::new(6, 10)];
+        merge(&mut ranges);
+        assert_eq!(ranges, [Range::new(1, 10)]);
+
+        let mut ranges = vec![
+            Range::new(12, 14),
+            Range::new(3, 8),
+            Range::new(1, 4),
+            Range::new(4, 5),
+            Range::new(20, 20),
+        ];
+        merge(&mut ranges);
+        assert_eq!(
+            ranges,
+            [Range::new(1, 8), Range::new(12, 14), Range::new(20, 20)]
+        );
+
+        let mut ranges = vec![];
+        merge(&mut ranges);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_size_contains() {
+        let a = Range::new(-2, 2);
+        assert_eq!(a.size(), 5);
+        assert!(a.contains(-2));
+        assert!(a.contains(2));
+        assert!(!a.contains(3));
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+/// Returns the result of `f` and the time taken to compute it
+///
+/// `f` : the computation to measure
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use advent_of_code_2023::timing::timed;
+/// let (sum, elapsed) = timed(|| (1..=10u64).sum::<u64>());
+/// assert_eq!(sum, 55);
+/// println!("computed in {elapsed:?}");
+/// ```
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_timed() {
+        let (value, elapsed) = timed(|| {
+            thread::sleep(Duration::from_millis(5));
+            42
+        });
+        assert_eq!(value, 42);
+        assert!(elapsed >= Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,31 @@
+//! Needs the input folder, which does not exist on wasm
+#![cfg(not(target_arch = "wasm32"))]
+
+use advent_of_code_2023::{day, error::AocError};
+
+/// The known correct answers of the committed inputs, as `(day, part 1, part 2)`
+const ANSWERS: &[(u8, u64, u64)] = &[(1, 56465, 55902)];
+
+#[test]
+fn test_known_answers() {
+    let days = day::all_days();
+
+    for (number, part1, part2) in ANSWERS {
+        let day = days
+            .iter()
+            .find(|day| day.number() == *number)
+            .unwrap_or_else(|| panic!("day {number} is not registered"));
+
+        let input = match day::read_input(*number) {
+            Ok(input) => input,
+            Err(AocError::MissingInput { .. }) => {
+                eprintln!("skipping day {number}, its input is missing");
+                continue;
+            }
+            Err(e) => panic!("cannot read input of day {number}: {e}"),
+        };
+
+        assert_eq!(day.part1(&input).unwrap(), *part1, "day {number} part 1");
+        assert_eq!(day.part2(&input).unwrap(), *part2, "day {number} part 2");
+    }
+}
@@ -0,0 +1,22 @@
+use std::fs;
+
+/// Returns the official example input of `day`, stored in `tests/fixtures/day{day}.txt`
+///
+/// Panics if the fixture is missing, as a test relying on it cannot run anyway
+pub fn example(day: u8) -> String {
+    fixture(&format!("day{day}.txt"))
+}
+
+/// Returns the example input of `part` of `day`, for the days whose parts have different examples,
+/// stored in `tests/fixtures/day{day}_part{part}.txt`
+///
+/// Panics if the fixture is missing
+#[allow(dead_code)]
+pub fn example_of_part(day: u8, part: u8) -> String {
+    fixture(&format!("day{day}_part{part}.txt"))
+}
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read fixture {path}: {e}"))
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use advent_of_code_2023::day1;
+
+#[test]
+fn test_day1_step1_example() {
+    let input = common::example_of_part(1, 1);
+    assert_eq!(day1::day1_step1_str(&input), 142);
+    assert_eq!(
+        day1::day1_values(&input).collect::<Vec<_>>(),
+        [12, 38, 15, 77]
+    );
+    assert_eq!(day1::day1_step1_buf(input.as_bytes()).unwrap(), 142);
+}
+
+#[test]
+fn test_day1_step2_example() {
+    let input = common::example(1);
+    assert_eq!(day1::day1_step2_str(&input), 281);
+    assert_eq!(
+        day1::day1_named_values(&input).collect::<Vec<_>>(),
+        [29, 83, 13, 24, 42, 14, 76]
+    );
+    assert_eq!(day1::day1_step2_buf(input.as_bytes()).unwrap(), 281);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_day1_from_path() {
+    use advent_of_code_2023::error::AocError;
+
+    let path = format!("{}/tests/fixtures/day1.txt", env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(day1::day1_step1_from_path(&path).unwrap(), 209);
+    assert_eq!(day1::day1_step2_from_path(&path).unwrap(), 281);
+
+    assert!(matches!(
+        day1::day1_step1_from_path("tests/fixtures/missing.txt"),
+        Err(AocError::MissingInput { day: 1 })
+    ));
+}
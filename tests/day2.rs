@@ -0,0 +1,21 @@
+mod common;
+
+use advent_of_code_2023::day2;
+
+#[test]
+fn test_day2_step1_example() {
+    let input = common::example(2);
+    assert_eq!(day2::day2_step1_str(&input).unwrap(), 8);
+}
+
+#[test]
+fn test_day2_step2_example() {
+    let input = common::example(2);
+    assert_eq!(day2::day2_step2_str(&input).unwrap(), 2286);
+}
+
+#[test]
+fn test_day2_both_example() {
+    let input = common::example(2);
+    assert_eq!(day2::day2_both(&input).unwrap(), (8, 2286));
+}
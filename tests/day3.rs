@@ -0,0 +1,21 @@
+mod common;
+
+use advent_of_code_2023::day3;
+
+#[test]
+fn test_day3_step1_example() {
+    let input = common::example(3);
+    assert_eq!(day3::day3_step1_str(&input), 4361);
+}
+
+#[test]
+fn test_day3_step2_example() {
+    let input = common::example(3);
+    assert_eq!(day3::day3_step2_str(&input), 467835);
+}
+
+#[test]
+fn test_day3_both_example() {
+    let input = common::example(3);
+    assert_eq!(day3::day3_both(&input), (4361, 467835));
+}
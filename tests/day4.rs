@@ -0,0 +1,29 @@
+mod common;
+
+use advent_of_code_2023::day4;
+
+#[test]
+fn test_day4_step1_example() {
+    let input = common::example(4);
+    assert_eq!(day4::day4_step1_str(&input), 13);
+}
+
+#[test]
+fn test_day4_step2_example() {
+    let input = common::example(4);
+    assert_eq!(day4::day4_step2_str(&input), 30);
+}
+
+#[test]
+fn test_day4_match_counts_example() {
+    let input = common::example(4);
+    assert_eq!(day4::day4_match_counts(&input), [4, 2, 2, 1, 0, 0]);
+}
+
+#[test]
+fn test_day4_blank_lines() {
+    // blank lines are not cards, they must not be copied nor scored
+    let input = format!("\n{}\n  \n", common::example(4));
+    assert_eq!(day4::day4_step1_str(&input), 13);
+    assert_eq!(day4::day4_step2_str(&input), 30);
+}
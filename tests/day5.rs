@@ -0,0 +1,36 @@
+mod common;
+
+use advent_of_code_2023::day5;
+
+#[test]
+fn test_day5_step1_example() {
+    let input = common::example(5);
+    assert_eq!(day5::day5_step1_str(&input).unwrap(), 35);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_day5_step1_par_example() {
+    let input = common::example(5);
+    assert_eq!(
+        day5::day5_step1_par(&input).unwrap(),
+        day5::day5_step1_str(&input).unwrap()
+    );
+    assert!(day5::day5_step1_par("seeds:\n").is_err());
+}
+
+#[test]
+fn test_day5_step2_example() {
+    let input = common::example(5);
+    assert_eq!(day5::day5_step2_str(&input).unwrap(), 46);
+}
+
+#[test]
+fn test_day5_step2_trace_example() {
+    let input = common::example(5);
+    let stages = day5::day5_step2_trace(&input).unwrap();
+    // the seed ranges, then one stage per map
+    assert_eq!(stages.len(), 8);
+    let lowest = stages.last().unwrap().iter().map(|(start, _)| *start).min();
+    assert_eq!(lowest, Some(46));
+}
@@ -0,0 +1,21 @@
+mod common;
+
+use advent_of_code_2023::day6;
+
+#[test]
+fn test_day6_step1_example() {
+    let input = common::example(6);
+    assert_eq!(day6::day6_step1_str(&input).unwrap(), 288);
+}
+
+#[test]
+fn test_day6_step2_example() {
+    let input = common::example(6);
+    assert_eq!(day6::day6_step2_str(&input).unwrap(), 71503);
+}
+
+#[test]
+fn test_day6_both_example() {
+    let input = common::example(6);
+    assert_eq!(day6::day6_both(&input).unwrap(), (288, 71503));
+}
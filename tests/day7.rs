@@ -0,0 +1,9 @@
+mod common;
+
+use advent_of_code_2023::day7;
+
+#[test]
+fn test_day7_step1_example() {
+    let input = common::example(7);
+    assert_eq!(day7::day7_step1_str(&input).unwrap(), 6440);
+}